@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, Env, symbol_short};
+use soroban_sdk::{contractevent, Address, Env, symbol_short};
 
 /// Emit event when liquidity is added
 pub fn liquidity_added(
@@ -42,3 +42,77 @@ pub fn swap(
         (sender, token_in, token_out, amount_in, amount_out),
     );
 }
+
+/// Swap with the post-swap reserves, so indexers can price trades from events alone
+///
+/// Published alongside the original `swap` event.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapDetailed {
+    pub sender: Address,
+    pub token_in: Address,
+    pub token_out: Address,
+    pub amount_in: i128,
+    pub amount_out: i128,
+    pub reserve_0: i128,
+    pub reserve_1: i128,
+    pub timestamp: u64,
+}
+
+/// Surplus balances skimmed to a recipient
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Skim {
+    pub to: Address,
+    pub amount_0: i128,
+    pub amount_1: i128,
+}
+
+/// Reserves synced to balances
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sync {
+    pub reserve_0: i128,
+    pub reserve_1: i128,
+}
+
+/// Emit detailed swap event with reserves after the swap
+#[allow(clippy::too_many_arguments)]
+pub fn swap_detailed(
+    env: &Env,
+    sender: &Address,
+    token_in: &Address,
+    token_out: &Address,
+    amount_in: i128,
+    amount_out: i128,
+    reserve_0: i128,
+    reserve_1: i128,
+) {
+    SwapDetailed {
+        sender: sender.clone(),
+        token_in: token_in.clone(),
+        token_out: token_out.clone(),
+        amount_in,
+        amount_out,
+        reserve_0,
+        reserve_1,
+        timestamp: env.ledger().timestamp(),
+    }.publish(env);
+}
+
+/// Emit event when surplus balances are skimmed
+pub fn skim(env: &Env, to: &Address, amount_0: i128, amount_1: i128) {
+    Skim {
+        to: to.clone(),
+        amount_0,
+        amount_1,
+    }.publish(env);
+}
+
+/// Emit event when reserves are synced to balances
+pub fn sync(env: &Env, reserve_0: i128, reserve_1: i128) {
+    Sync {
+        reserve_0,
+        reserve_1,
+    }.publish(env);
+}
@@ -13,14 +13,19 @@
 //! - Flash swap support (future)
 
 use soroban_sdk::{
-    contract, contractimpl, contracttype, token, Address, Env, String, Symbol, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error, token, Address, Env, String, Symbol, symbol_short,
 };
 
+mod errors;
 mod math;
 mod storage;
 mod events;
 mod reentrancy;
 
+#[cfg(test)]
+mod tests;
+
+use errors::Error;
 use storage::{DataKey, PairInfo};
 
 /// Minimum liquidity to lock permanently (prevents division by zero attacks)
@@ -30,6 +35,9 @@ const MINIMUM_LIQUIDITY: i128 = 1000;
 const FEE_BPS: i128 = 30;
 const FEE_DENOMINATOR: i128 = 10000;
 
+/// Contract version, bumped on every deploy that changes behavior or signatures
+const CONTRACT_VERSION: u32 = 1;
+
 #[contract]
 pub struct AMMPair;
 
@@ -69,9 +77,11 @@ impl AMMPair {
             reserve_1: 0,
             total_supply: 0,
             k_last: 0,
+            lp_holder_count: 0,
         };
 
         storage::set_pair_info(&env, &pair_info);
+        storage::set_deployed_version(&env, CONTRACT_VERSION);
     }
 
     /// Add liquidity to the pair
@@ -85,7 +95,8 @@ impl AMMPair {
     /// * `deadline` - Unix timestamp after which transaction expires (MEV protection)
     ///
     /// # Returns
-    /// Tuple of (amount0, amount1, liquidity_minted)
+    /// Tuple of (amount0, amount1, liquidity_minted), with the amounts the pair
+    /// actually received (less than sent for fee-on-transfer tokens)
     pub fn add_liquidity(
         env: Env,
         sender: Address,
@@ -138,6 +149,19 @@ impl AMMPair {
             }
         };
 
+        // Transfer tokens from sender to this contract, crediting only what
+        // arrives (fee-on-transfer tokens deliver less than requested)
+        let amount_0 = Self::transfer_in(&env, &pair_info.token_0, &sender, amount_0);
+        let amount_1 = Self::transfer_in(&env, &pair_info.token_1, &sender, amount_1);
+
+        // Slippage limits apply to what was received, not what was requested
+        if amount_0 < amount_0_min {
+            panic!("insufficient token0 amount");
+        }
+        if amount_1 < amount_1_min {
+            panic!("insufficient token1 amount");
+        }
+
         // Calculate liquidity to mint
         let liquidity = if pair_info.total_supply == 0 {
             // First liquidity: sqrt(amount0 * amount1) - MINIMUM_LIQUIDITY
@@ -168,12 +192,8 @@ impl AMMPair {
             panic!("insufficient liquidity minted");
         }
 
-        // Transfer tokens from sender to this contract
-        let token_0_client = token::Client::new(&env, &pair_info.token_0);
-        let token_1_client = token::Client::new(&env, &pair_info.token_1);
-
-        token_0_client.transfer(&sender, &env.current_contract_address(), &amount_0);
-        token_1_client.transfer(&sender, &env.current_contract_address(), &amount_1);
+        // Accrue cumulative prices at the reserves being replaced
+        Self::update_cumulative_prices(&env, pair_info.reserve_0, pair_info.reserve_1);
 
         // Update reserves
         pair_info.reserve_0 += amount_0;
@@ -181,6 +201,9 @@ impl AMMPair {
         pair_info.total_supply += liquidity;
 
         // Store LP balance for sender
+        if storage::get_balance(&env, &sender) == 0 {
+            pair_info.lp_holder_count += 1;
+        }
         storage::increase_balance(&env, &sender, liquidity);
 
         storage::set_pair_info(&env, &pair_info);
@@ -228,9 +251,14 @@ impl AMMPair {
             panic!("insufficient liquidity");
         }
 
-        // Calculate amounts to return
-        let amount_0 = (liquidity * pair_info.reserve_0) / pair_info.total_supply;
-        let amount_1 = (liquidity * pair_info.reserve_1) / pair_info.total_supply;
+        // Calculate amounts to return (reverts on dust burns)
+        let (amount_0, amount_1) = math::liquidity_removal_amounts(
+            liquidity,
+            pair_info.reserve_0,
+            pair_info.reserve_1,
+            pair_info.total_supply,
+        )
+        .unwrap_or_else(|error| panic_with_error!(&env, error));
 
         // Check slippage
         if amount_0 < amount_0_min {
@@ -243,6 +271,12 @@ impl AMMPair {
         // Burn LP tokens
         storage::decrease_balance(&env, &sender, liquidity);
         pair_info.total_supply -= liquidity;
+        if liquidity > 0 && sender_balance == liquidity {
+            pair_info.lp_holder_count -= 1;
+        }
+
+        // Accrue cumulative prices at the reserves being replaced
+        Self::update_cumulative_prices(&env, pair_info.reserve_0, pair_info.reserve_1);
 
         // Update reserves
         pair_info.reserve_0 -= amount_0;
@@ -267,7 +301,8 @@ impl AMMPair {
     ///
     /// # Arguments
     /// * `sender` - Address performing the swap
-    /// * `amount_in` - Exact amount of input token
+    /// * `amount_in` - Exact amount of input token sent (output is priced on the
+    ///   amount that arrives, which is less for fee-on-transfer tokens)
     /// * `amount_out_min` - Minimum amount of output token (slippage protection)
     /// * `token_in` - Address of input token
     /// * `deadline` - Unix timestamp after which transaction expires (MEV protection)
@@ -310,6 +345,13 @@ impl AMMPair {
         // CRITICAL FIX: Calculate K BEFORE any state changes
         let k_old = reserve_in * reserve_out;
 
+        // Transfer input tokens from sender to this contract; the output is
+        // priced on what actually arrived (fee-on-transfer tokens deliver less)
+        let amount_in = Self::transfer_in(&env, &token_in, &sender, amount_in);
+        if amount_in <= 0 {
+            panic!("insufficient input amount");
+        }
+
         // Calculate output amount with fee
         let amount_out = math::get_amount_out(amount_in, reserve_in, reserve_out);
 
@@ -317,39 +359,102 @@ impl AMMPair {
             panic!("insufficient output amount");
         }
 
-        // Transfer input tokens from sender to this contract
-        let token_in_client = token::Client::new(&env, &token_in);
-        token_in_client.transfer(&sender, &env.current_contract_address(), &amount_in);
-
         // Transfer output tokens to sender
         let token_out_client = token::Client::new(&env, &token_out);
         token_out_client.transfer(&env.current_contract_address(), &sender, &amount_out);
 
-        // Update reserves
-        if token_in == pair_info.token_0 {
-            pair_info.reserve_0 += amount_in;
-            pair_info.reserve_1 -= amount_out;
-        } else {
-            pair_info.reserve_1 += amount_in;
-            pair_info.reserve_0 -= amount_out;
-        }
+        // Accrue cumulative prices at the reserves being replaced
+        Self::update_cumulative_prices(&env, pair_info.reserve_0, pair_info.reserve_1);
+
+        // Update reserves from the actual balances, not the requested amounts:
+        // a token that under-delivers (fee-on-transfer, or lying about the
+        // transfer) shows up here as a shortfall
+        let contract_address = env.current_contract_address();
+        pair_info.reserve_0 = token::Client::new(&env, &pair_info.token_0).balance(&contract_address);
+        pair_info.reserve_1 = token::Client::new(&env, &pair_info.token_1).balance(&contract_address);
 
         // CRITICAL FIX: Verify K invariant - K should INCREASE due to fees
         let k_new = pair_info.reserve_0 * pair_info.reserve_1;
 
-        // K must be greater than or equal to k_old (fees ensure K increases)
+        // K must be greater than k_old (fees ensure K increases)
         if k_new <= k_old {
-            panic!("K invariant violated - new K must be > old K due to fees");
+            panic_with_error!(&env, Error::KInvariantViolated);
         }
 
         storage::set_pair_info(&env, &pair_info);
 
-        // Emit event
+        // Emit events (basic and detailed)
         events::swap(&env, &sender, &token_in, &token_out, amount_in, amount_out);
+        events::swap_detailed(
+            &env,
+            &sender,
+            &token_in,
+            &token_out,
+            amount_in,
+            amount_out,
+            pair_info.reserve_0,
+            pair_info.reserve_1,
+        );
 
         amount_out
     }
 
+    /// Send token balances above the reserves to `to`
+    ///
+    /// Recovers tokens transferred to the pair directly (outside `add_liquidity`),
+    /// leaving the reserves untouched. Anyone may call it.
+    ///
+    /// # Returns
+    /// Tuple of (amount0, amount1) skimmed
+    pub fn skim(env: Env, to: Address) -> (i128, i128) {
+        // REENTRANCY PROTECTION: Acquire lock for entire function
+        let _guard = reentrancy::ReentrancyGuard::new(&env);
+
+        let pair_info = storage::get_pair_info(&env);
+        let contract_address = env.current_contract_address();
+
+        let token_0_client = token::Client::new(&env, &pair_info.token_0);
+        let token_1_client = token::Client::new(&env, &pair_info.token_1);
+
+        let excess_0 = (token_0_client.balance(&contract_address) - pair_info.reserve_0).max(0);
+        let excess_1 = (token_1_client.balance(&contract_address) - pair_info.reserve_1).max(0);
+
+        if excess_0 > 0 {
+            token_0_client.transfer(&contract_address, &to, &excess_0);
+        }
+        if excess_1 > 0 {
+            token_1_client.transfer(&contract_address, &to, &excess_1);
+        }
+
+        // Emit event
+        events::skim(&env, &to, excess_0, excess_1);
+
+        (excess_0, excess_1)
+    }
+
+    /// Set the reserves to the pair's actual token balances
+    ///
+    /// Fixes reserves that drifted from balances (e.g. after a direct transfer),
+    /// so the surplus joins the pool for all LPs. Anyone may call it.
+    pub fn sync(env: Env) {
+        // REENTRANCY PROTECTION: Acquire lock for entire function
+        let _guard = reentrancy::ReentrancyGuard::new(&env);
+
+        let mut pair_info = storage::get_pair_info(&env);
+        let contract_address = env.current_contract_address();
+
+        // Accrue cumulative prices at the reserves being replaced
+        Self::update_cumulative_prices(&env, pair_info.reserve_0, pair_info.reserve_1);
+
+        pair_info.reserve_0 = token::Client::new(&env, &pair_info.token_0).balance(&contract_address);
+        pair_info.reserve_1 = token::Client::new(&env, &pair_info.token_1).balance(&contract_address);
+
+        storage::set_pair_info(&env, &pair_info);
+
+        // Emit event
+        events::sync(&env, pair_info.reserve_0, pair_info.reserve_1);
+    }
+
     /// Get current reserves
     ///
     /// # Returns
@@ -361,6 +466,33 @@ impl AMMPair {
         (pair_info.reserve_0, pair_info.reserve_1, timestamp)
     }
 
+    /// Get the cumulative prices for building an external TWAP
+    ///
+    /// Sample twice and divide the (wrapping) difference by the elapsed time to get
+    /// the average price over any window you choose. The values advance on every
+    /// swap, liquidity change or sync.
+    ///
+    /// # Returns
+    /// Tuple of (price0_cumulative, price1_cumulative, last_update_timestamp)
+    pub fn get_cumulative_prices(env: Env) -> (i128, i128, u64) {
+        let prices = storage::get_cumulative_prices(&env);
+
+        (prices.price_0_cumulative, prices.price_1_cumulative, prices.timestamp)
+    }
+
+    /// Get the contract version of this code
+    ///
+    /// Clients compare it to the version they were built against before
+    /// calling functions whose signatures may have changed.
+    pub fn version(_env: Env) -> u32 {
+        CONTRACT_VERSION
+    }
+
+    /// Get the contract version this pair was initialized with (0 if older than the marker)
+    pub fn get_deployed_version(env: Env) -> u32 {
+        storage::get_deployed_version(&env)
+    }
+
     /// Get pair information
     pub fn get_pair_info(env: Env) -> PairInfo {
         storage::get_pair_info(&env)
@@ -377,6 +509,23 @@ impl AMMPair {
         pair_info.total_supply
     }
 
+    /// Get the LP supply permanently locked by the first deposit
+    ///
+    /// The locked portion counts toward `total_supply` but is not credited to
+    /// any address, so it can never be redeemed.
+    ///
+    /// # Returns
+    /// 0 before the first deposit, `MINIMUM_LIQUIDITY` afterwards
+    pub fn minimum_liquidity_locked(env: Env) -> i128 {
+        let pair_info = storage::get_pair_info(&env);
+
+        if pair_info.total_supply == 0 {
+            0
+        } else {
+            MINIMUM_LIQUIDITY
+        }
+    }
+
     /// Calculate output amount for a given input (without executing swap)
     pub fn get_amount_out(env: Env, amount_in: i128, token_in: Address) -> i128 {
         let pair_info = storage::get_pair_info(&env);
@@ -392,6 +541,24 @@ impl AMMPair {
         math::get_amount_out(amount_in, reserve_in, reserve_out)
     }
 
+    /// Get instantaneous price of `token` in terms of the other pair token
+    ///
+    /// # Returns
+    /// `other_reserve * PRICE_PRECISION / this_reserve` (PRICE_PRECISION = 1e9)
+    pub fn get_spot_price(env: Env, token: Address) -> Result<i128, Error> {
+        let pair_info = storage::get_pair_info(&env);
+
+        let (this_reserve, other_reserve) = if token == pair_info.token_0 {
+            (pair_info.reserve_0, pair_info.reserve_1)
+        } else if token == pair_info.token_1 {
+            (pair_info.reserve_1, pair_info.reserve_0)
+        } else {
+            return Err(Error::InvalidToken);
+        };
+
+        math::spot_price(this_reserve, other_reserve)
+    }
+
     /// Calculate input amount needed for a desired output (without executing swap)
     pub fn get_amount_in(env: Env, amount_out: i128, token_out: Address) -> i128 {
         let pair_info = storage::get_pair_info(&env);
@@ -406,4 +573,44 @@ impl AMMPair {
 
         math::get_amount_in(amount_out, reserve_in, reserve_out)
     }
+
+    // ========== Internal Functions ==========
+
+    /// Transfer `amount` of `token` from `from` to the pair
+    ///
+    /// # Returns
+    /// The increase in the pair's balance, which is less than `amount` for
+    /// fee-on-transfer tokens
+    fn transfer_in(env: &Env, token: &Address, from: &Address, amount: i128) -> i128 {
+        let token_client = token::Client::new(env, token);
+        let contract_address = env.current_contract_address();
+
+        let balance_before = token_client.balance(&contract_address);
+        token_client.transfer(from, &contract_address, &amount);
+
+        token_client.balance(&contract_address) - balance_before
+    }
+
+    /// Add `price * seconds_elapsed` at the current reserves to the cumulative prices
+    ///
+    /// Call before changing reserves. Skipped while either reserve is empty
+    /// (or the price itself would overflow).
+    fn update_cumulative_prices(env: &Env, reserve_0: i128, reserve_1: i128) {
+        let mut prices = storage::get_cumulative_prices(env);
+        let now = env.ledger().timestamp();
+        let elapsed = now.saturating_sub(prices.timestamp) as i128;
+
+        if elapsed > 0 {
+            if let (Ok(price_0), Ok(price_1)) = (
+                math::spot_price(reserve_0, reserve_1),
+                math::spot_price(reserve_1, reserve_0),
+            ) {
+                prices.price_0_cumulative = prices.price_0_cumulative.wrapping_add(price_0.wrapping_mul(elapsed));
+                prices.price_1_cumulative = prices.price_1_cumulative.wrapping_add(price_1.wrapping_mul(elapsed));
+            }
+        }
+
+        prices.timestamp = now;
+        storage::set_cumulative_prices(env, &prices);
+    }
 }
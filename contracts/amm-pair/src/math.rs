@@ -1,10 +1,15 @@
 /// Math library for AMM calculations
 /// Based on Uniswap V2 math
 
+use crate::errors::Error;
+
 /// Fee in basis points (30 = 0.3%)
 const FEE_BPS: i128 = 30;
 const FEE_DENOMINATOR: i128 = 10000;
 
+/// Fixed-point precision for prices (matches the TWAP oracle)
+pub const PRICE_PRECISION: i128 = 1_000_000_000;
+
 /// Calculate square root using Babylonian method
 /// Used for initial liquidity calculation
 pub fn sqrt(y: i128) -> i128 {
@@ -104,6 +109,71 @@ pub fn get_amount_in(amount_out: i128, reserve_in: i128, reserve_out: i128) -> i
     (numerator / denominator) + 1
 }
 
+/// Calculate spot price of a token in terms of the other token
+/// Formula: price = other_reserve * PRICE_PRECISION / this_reserve
+///
+/// # Arguments
+/// * `this_reserve` - Reserve of the token being priced
+/// * `other_reserve` - Reserve of the quote token
+///
+/// # Returns
+/// Price scaled by PRICE_PRECISION
+pub fn spot_price(this_reserve: i128, other_reserve: i128) -> Result<i128, Error> {
+    if this_reserve <= 0 {
+        return Err(Error::InsufficientLiquidity);
+    }
+
+    other_reserve.checked_mul(PRICE_PRECISION).ok_or(Error::Overflow)?
+        .checked_div(this_reserve).ok_or(Error::DivisionByZero)
+}
+
+/// Calculate token amounts returned for burning LP tokens
+/// Formula: amount = liquidity * reserve / total_supply (rounded down)
+///
+/// Rounding always favors the pool, so the backing per LP token for the
+/// remaining holders never decreases. That invariant is checked explicitly.
+///
+/// # Arguments
+/// * `liquidity` - LP tokens being burned
+/// * `reserve_0` - Reserve of token 0
+/// * `reserve_1` - Reserve of token 1
+/// * `total_supply` - LP total supply before the burn
+///
+/// # Returns
+/// (amount_0, amount_1), or `InsufficientLiquidity` if either rounds to zero
+pub fn liquidity_removal_amounts(
+    liquidity: i128,
+    reserve_0: i128,
+    reserve_1: i128,
+    total_supply: i128,
+) -> Result<(i128, i128), Error> {
+    if liquidity <= 0 || liquidity > total_supply {
+        return Err(Error::InvalidAmount);
+    }
+
+    let amount_0 = liquidity.checked_mul(reserve_0).ok_or(Error::Overflow)?
+        .checked_div(total_supply).ok_or(Error::DivisionByZero)?;
+    let amount_1 = liquidity.checked_mul(reserve_1).ok_or(Error::Overflow)?
+        .checked_div(total_supply).ok_or(Error::DivisionByZero)?;
+
+    // Dust burns must not silently destroy LP tokens for nothing
+    if amount_0 <= 0 || amount_1 <= 0 {
+        return Err(Error::InsufficientLiquidity);
+    }
+
+    // Remover never gets more than their pro-rata share:
+    // amount * total_supply <= liquidity * reserve
+    for (amount, reserve) in [(amount_0, reserve_0), (amount_1, reserve_1)] {
+        let paid = amount.checked_mul(total_supply).ok_or(Error::Overflow)?;
+        let share = liquidity.checked_mul(reserve).ok_or(Error::Overflow)?;
+        if paid > share {
+            return Err(Error::InsufficientLiquidity);
+        }
+    }
+
+    Ok((amount_0, amount_1))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -196,4 +266,58 @@ mod tests {
     fn test_get_amount_in_exceeds_reserve() {
         get_amount_in(1001, 1000, 1000);
     }
+
+    #[test]
+    fn test_liquidity_removal_amounts() {
+        // Pro-rata share of both reserves
+        assert_eq!(liquidity_removal_amounts(100, 10_000, 20_000, 1_000).unwrap(), (1_000, 2_000));
+
+        // Rounding favors the pool: 1 * 1_999 / 1_000 = 1 (not 1.999)
+        assert_eq!(liquidity_removal_amounts(1, 1_999, 5_000, 1_000).unwrap(), (1, 5));
+
+        // Burning everything returns the full reserves
+        assert_eq!(liquidity_removal_amounts(1_000, 10_000, 20_000, 1_000).unwrap(), (10_000, 20_000));
+    }
+
+    #[test]
+    fn test_liquidity_removal_dust_fails() {
+        // 1 LP of 1_000 against a 999 reserve rounds to zero token0
+        assert_eq!(
+            liquidity_removal_amounts(1, 999, 1_000_000, 1_000).unwrap_err(),
+            Error::InsufficientLiquidity
+        );
+
+        // Smallest burn that pays out something on both sides
+        assert_eq!(liquidity_removal_amounts(2, 999, 1_000_000, 1_000).unwrap(), (1, 2_000));
+
+        assert_eq!(liquidity_removal_amounts(0, 1_000, 1_000, 1_000).unwrap_err(), Error::InvalidAmount);
+        assert_eq!(liquidity_removal_amounts(1_001, 1_000, 1_000, 1_000).unwrap_err(), Error::InvalidAmount);
+    }
+
+    #[test]
+    fn test_liquidity_removal_keeps_backing_for_remaining_holders() {
+        let (reserve_0, reserve_1, total_supply) = (1_000_003, 7_777_777, 333_333);
+
+        for liquidity in [1, 7, 1_000, 333_332] {
+            let (amount_0, amount_1) =
+                liquidity_removal_amounts(liquidity, reserve_0, reserve_1, total_supply).unwrap();
+
+            // (reserve - amount) / (supply - liquidity) >= reserve / supply
+            let remaining_supply = total_supply - liquidity;
+            assert!((reserve_0 - amount_0) * total_supply >= reserve_0 * remaining_supply);
+            assert!((reserve_1 - amount_1) * total_supply >= reserve_1 * remaining_supply);
+        }
+    }
+
+    #[test]
+    fn test_spot_price() {
+        // 1:1 ratio
+        assert_eq!(spot_price(1000, 1000).unwrap(), PRICE_PRECISION);
+
+        // Token is scarcer than the quote token: 1 token = 2 quote
+        assert_eq!(spot_price(1000, 2000).unwrap(), 2 * PRICE_PRECISION);
+
+        // Empty reserve has no price
+        assert_eq!(spot_price(0, 1000).unwrap_err(), Error::InsufficientLiquidity);
+    }
 }
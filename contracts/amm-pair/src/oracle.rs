@@ -153,14 +153,7 @@ impl Oracle {
 
     /// Get current spot price
     pub fn get_spot_price(&self, reserve_0: i128, reserve_1: i128) -> Result<i128, Error> {
-        if reserve_0 == 0 {
-            return Err(Error::InsufficientLiquidity);
-        }
-
-        const PRECISION: i128 = 1_000_000_000;
-
-        reserve_1.checked_mul(PRECISION).ok_or(Error::Overflow)?
-            .checked_div(reserve_0).ok_or(Error::DivisionByZero)
+        crate::math::spot_price(reserve_0, reserve_1)
     }
 }
 
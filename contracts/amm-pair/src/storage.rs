@@ -8,6 +8,10 @@ pub enum DataKey {
     PairInfo,
     /// LP token balance for an address
     Balance(Address),
+    /// Contract version recorded at initialize
+    DeployedVersion,
+    /// Cumulative prices for external TWAP oracles
+    CumulativePrices,
 }
 
 /// Pair information and state
@@ -30,6 +34,24 @@ pub struct PairInfo {
     pub total_supply: i128,
     /// Last K value (for protocol fee calculation)
     pub k_last: i128,
+    /// Addresses holding a nonzero LP balance (the locked minimum liquidity has no holder)
+    pub lp_holder_count: u32,
+}
+
+/// Time-weighted cumulative prices (Uniswap V2 `price0CumulativeLast` style)
+///
+/// Each update adds `spot_price * seconds_elapsed` using the reserves in force
+/// over that interval. Sums wrap on overflow, so consumers should difference
+/// two samples with wrapping subtraction.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CumulativePrices {
+    /// Sum of token0's price (in token1, scaled by PRICE_PRECISION) over time
+    pub price_0_cumulative: i128,
+    /// Sum of token1's price (in token0, scaled by PRICE_PRECISION) over time
+    pub price_1_cumulative: i128,
+    /// Ledger timestamp of the last update
+    pub timestamp: u64,
 }
 
 // Pair info functions
@@ -48,6 +70,27 @@ pub fn has_pair_info(env: &Env) -> bool {
     env.storage().instance().has(&DataKey::PairInfo)
 }
 
+// Cumulative price functions
+pub fn get_cumulative_prices(env: &Env) -> CumulativePrices {
+    env.storage()
+        .instance()
+        .get(&DataKey::CumulativePrices)
+        .unwrap_or_default()
+}
+
+pub fn set_cumulative_prices(env: &Env, prices: &CumulativePrices) {
+    env.storage().instance().set(&DataKey::CumulativePrices, prices);
+}
+
+// Version marker functions
+pub fn set_deployed_version(env: &Env, version: u32) {
+    env.storage().instance().set(&DataKey::DeployedVersion, &version);
+}
+
+pub fn get_deployed_version(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::DeployedVersion).unwrap_or(0)
+}
+
 // Balance functions (LP tokens)
 pub fn get_balance(env: &Env, address: &Address) -> i128 {
     let key = DataKey::Balance(address.clone());
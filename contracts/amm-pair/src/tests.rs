@@ -0,0 +1,371 @@
+//! Contract tests for AMM Pair
+//!
+//! Run through the contract client against real Stellar Asset Contracts.
+
+use crate::{AMMPair, AMMPairClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+
+fn create_token<'a>(env: &Env) -> (Address, StellarAssetClient<'a>) {
+    let address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let admin_client = StellarAssetClient::new(env, &address);
+    (address, admin_client)
+}
+
+/// Pair seeded with 1000 units of each token by `provider`
+fn setup_pair<'a>(env: &Env) -> (AMMPairClient<'a>, TokenClient<'a>, TokenClient<'a>) {
+    env.mock_all_auths();
+
+    let (token_a, admin_a) = create_token(env);
+    let (token_b, admin_b) = create_token(env);
+
+    let pair_address = env.register(AMMPair, ());
+    let pair = AMMPairClient::new(env, &pair_address);
+    pair.initialize(&token_a, &token_b, &Address::generate(env), &Address::generate(env));
+
+    let provider = Address::generate(env);
+    admin_a.mint(&provider, &10_000_000_000);
+    admin_b.mint(&provider, &10_000_000_000);
+    pair.add_liquidity(&provider, &10_000_000_000, &10_000_000_000, &0, &0, &u64::MAX);
+
+    let info = pair.get_pair_info();
+    (
+        pair,
+        TokenClient::new(env, &info.token_0),
+        TokenClient::new(env, &info.token_1),
+    )
+}
+
+fn donate(env: &Env, token: &TokenClient, to: &Address, amount: i128) {
+    let donor = Address::generate(env);
+    StellarAssetClient::new(env, &token.address).mint(&donor, &amount);
+    token.transfer(&donor, to, &amount);
+}
+
+// ========== Skim / Sync Tests ==========
+
+#[test]
+fn test_skim_returns_donated_tokens() {
+    let env = Env::default();
+    let (pair, token_0, token_1) = setup_pair(&env);
+
+    donate(&env, &token_0, &pair.address, 500_000_000);
+    donate(&env, &token_1, &pair.address, 70_000_000);
+
+    let recipient = Address::generate(&env);
+    let (skimmed_0, skimmed_1) = pair.skim(&recipient);
+
+    assert_eq!((skimmed_0, skimmed_1), (500_000_000, 70_000_000));
+    assert_eq!(token_0.balance(&recipient), 500_000_000);
+    assert_eq!(token_1.balance(&recipient), 70_000_000);
+
+    // Reserves are untouched and match balances again
+    let (reserve_0, reserve_1, _) = pair.get_reserves();
+    assert_eq!((reserve_0, reserve_1), (10_000_000_000, 10_000_000_000));
+    assert_eq!(token_0.balance(&pair.address), reserve_0);
+    assert_eq!(token_1.balance(&pair.address), reserve_1);
+
+    // Nothing left to skim
+    assert_eq!(pair.skim(&recipient), (0, 0));
+}
+
+#[test]
+fn test_sync_fixes_desynced_reserves() {
+    let env = Env::default();
+    let (pair, token_0, token_1) = setup_pair(&env);
+
+    donate(&env, &token_0, &pair.address, 250_000_000);
+
+    pair.sync();
+
+    let (reserve_0, reserve_1, _) = pair.get_reserves();
+    assert_eq!(reserve_0, 10_250_000_000);
+    assert_eq!(reserve_1, 10_000_000_000);
+    assert_eq!(token_0.balance(&pair.address), reserve_0);
+    assert_eq!(token_1.balance(&pair.address), reserve_1);
+}
+
+// ========== Cumulative Price Tests ==========
+
+#[test]
+fn test_cumulative_prices_advance_between_updates() {
+    let env = Env::default();
+    let (pair, token_0, _token_1) = setup_pair(&env);
+    let trader = Address::generate(&env);
+    StellarAssetClient::new(&env, &token_0.address).mint(&trader, &1_000_000_000);
+
+    let (start_0, start_1, start_time) = pair.get_cumulative_prices();
+
+    // 1:1 reserves for 100 seconds, then a swap records them
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    pair.swap(&trader, &100_000_000, &0, &token_0.address, &u64::MAX);
+
+    let (mid_0, mid_1, mid_time) = pair.get_cumulative_prices();
+    assert_eq!(mid_time, start_time + 100);
+    assert_eq!(mid_0 - start_0, crate::math::PRICE_PRECISION * 100);
+    assert_eq!(mid_1 - start_1, crate::math::PRICE_PRECISION * 100);
+
+    // Token0 is now cheaper, so its cumulative grows slower than token1's
+    env.ledger().with_mut(|li| li.timestamp += 50);
+    pair.sync();
+
+    let (end_0, end_1, end_time) = pair.get_cumulative_prices();
+    assert_eq!(end_time, mid_time + 50);
+    assert!(end_0 - mid_0 < crate::math::PRICE_PRECISION * 50);
+    assert!(end_1 - mid_1 > crate::math::PRICE_PRECISION * 50);
+}
+
+// ========== Swap Event Tests ==========
+
+#[test]
+fn test_swap_detailed_event_reports_post_swap_reserves() {
+    use soroban_sdk::{testutils::Events as _, Map, Symbol, TryFromVal, Val};
+
+    let env = Env::default();
+    let (pair, token_0, token_1) = setup_pair(&env);
+    let trader = Address::generate(&env);
+    StellarAssetClient::new(&env, &token_0.address).mint(&trader, &1_000_000_000);
+
+    let amount_out = pair.swap(&trader, &100_000_000, &0, &token_0.address, &u64::MAX);
+
+    let topic = Symbol::new(&env, "swap_detailed");
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics.get(0).and_then(|t| Symbol::try_from_val(&env, &t).ok()) == Some(topic.clone())
+        })
+        .last()
+        .expect("swap_detailed not published");
+    let data = Map::<Symbol, Val>::try_from_val(&env, &data).unwrap();
+    let field = |name: &str| data.get(Symbol::new(&env, name)).unwrap();
+
+    let (reserve_0, reserve_1, _) = pair.get_reserves();
+    assert_eq!(i128::try_from_val(&env, &field("reserve_0")).unwrap(), reserve_0);
+    assert_eq!(i128::try_from_val(&env, &field("reserve_1")).unwrap(), reserve_1);
+    assert_eq!(i128::try_from_val(&env, &field("amount_out")).unwrap(), amount_out);
+    assert_eq!(Address::try_from_val(&env, &field("token_out")).unwrap(), token_1.address);
+    assert_eq!(Address::try_from_val(&env, &field("sender")).unwrap(), trader);
+}
+
+// ========== Minimum Liquidity Tests ==========
+
+#[test]
+fn test_minimum_liquidity_stays_locked_after_full_withdrawal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (token_a, admin_a) = create_token(&env);
+    let (token_b, admin_b) = create_token(&env);
+
+    let pair = AMMPairClient::new(&env, &env.register(AMMPair, ()));
+    pair.initialize(&token_a, &token_b, &Address::generate(&env), &Address::generate(&env));
+    assert_eq!(pair.minimum_liquidity_locked(), 0);
+
+    let provider = Address::generate(&env);
+    admin_a.mint(&provider, &10_000_000_000);
+    admin_b.mint(&provider, &10_000_000_000);
+    let (_, _, minted) = pair.add_liquidity(&provider, &10_000_000_000, &10_000_000_000, &0, &0, &u64::MAX);
+
+    // Locked amount is part of total_supply but held by nobody
+    let locked = pair.minimum_liquidity_locked();
+    assert_eq!(locked, crate::MINIMUM_LIQUIDITY);
+    assert_eq!(pair.total_supply(), minted + locked);
+    assert_eq!(pair.balance_of(&provider), minted);
+
+    pair.remove_liquidity(&provider, &minted, &0, &0, &u64::MAX);
+
+    assert_eq!(pair.balance_of(&provider), 0);
+    assert_eq!(pair.total_supply(), locked);
+    assert_eq!(pair.minimum_liquidity_locked(), locked);
+    // Locked supply remains, but nobody holds it
+    assert_eq!(pair.get_pair_info().lp_holder_count, 0);
+
+    let (reserve_0, reserve_1, _) = pair.get_reserves();
+    assert!(reserve_0 > 0 && reserve_1 > 0);
+}
+
+// ========== LP Holder Tests ==========
+
+#[test]
+fn test_lp_holder_count_tracks_nonzero_balances() {
+    let env = Env::default();
+    let (pair, token_0, token_1) = setup_pair(&env);
+
+    // The seeding provider holds LP; the locked minimum liquidity holds no one
+    assert_eq!(pair.get_pair_info().lp_holder_count, 1);
+
+    let lp = Address::generate(&env);
+    StellarAssetClient::new(&env, &token_0.address).mint(&lp, &2_000_000_000);
+    StellarAssetClient::new(&env, &token_1.address).mint(&lp, &2_000_000_000);
+
+    let (_, _, first) = pair.add_liquidity(&lp, &1_000_000_000, &1_000_000_000, &0, &0, &u64::MAX);
+    assert_eq!(pair.get_pair_info().lp_holder_count, 2);
+
+    // Topping up an existing position adds no holder
+    let (_, _, second) = pair.add_liquidity(&lp, &1_000_000_000, &1_000_000_000, &0, &0, &u64::MAX);
+    assert_eq!(pair.get_pair_info().lp_holder_count, 2);
+
+    // A partial exit keeps the holder, a full exit removes it
+    pair.remove_liquidity(&lp, &first, &0, &0, &u64::MAX);
+    assert_eq!(pair.get_pair_info().lp_holder_count, 2);
+    pair.remove_liquidity(&lp, &second, &0, &0, &u64::MAX);
+    assert_eq!(pair.balance_of(&lp), 0);
+    assert_eq!(pair.get_pair_info().lp_holder_count, 1);
+}
+
+// ========== Non-Standard Token Tests ==========
+
+/// Minimal token that burns `burn_bps` of every transfer in flight, and can
+/// debit senders `surcharge_bps` on top of the amount sent
+#[soroban_sdk::contract]
+struct DeflationaryToken;
+
+#[soroban_sdk::contractimpl]
+impl DeflationaryToken {
+    pub fn set_burn_bps(env: Env, burn_bps: i128) {
+        env.storage().instance().set(&soroban_sdk::symbol_short!("burn"), &burn_bps);
+    }
+
+    pub fn set_surcharge_bps(env: Env, surcharge_bps: i128) {
+        env.storage().instance().set(&soroban_sdk::symbol_short!("surcharge"), &surcharge_bps);
+    }
+
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().persistent().set(&to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().persistent().get(&id).unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let bps = |key| -> i128 { env.storage().instance().get(&key).unwrap_or(0) };
+        let burned = amount * bps(soroban_sdk::symbol_short!("burn")) / 10_000;
+        let surcharge = amount * bps(soroban_sdk::symbol_short!("surcharge")) / 10_000;
+
+        let from_balance = Self::balance(env.clone(), from.clone());
+        env.storage().persistent().set(&from, &(from_balance - amount - surcharge));
+        Self::mint(env, to, amount - burned);
+    }
+}
+
+/// Pair of a real SAC and a `DeflationaryToken`, seeded 1000/1000 before any burn
+fn setup_deflationary_pair<'a>(env: &Env) -> (AMMPairClient<'a>, TokenClient<'a>, DeflationaryTokenClient<'a>) {
+    env.mock_all_auths();
+
+    let (token_a, admin_a) = create_token(env);
+    let deflationary = DeflationaryTokenClient::new(env, &env.register(DeflationaryToken, ()));
+
+    let pair = AMMPairClient::new(env, &env.register(AMMPair, ()));
+    pair.initialize(&token_a, &deflationary.address, &Address::generate(env), &Address::generate(env));
+
+    let provider = Address::generate(env);
+    admin_a.mint(&provider, &10_000_000_000);
+    deflationary.mint(&provider, &10_000_000_000);
+    pair.add_liquidity(&provider, &10_000_000_000, &10_000_000_000, &0, &0, &u64::MAX);
+
+    (pair, TokenClient::new(env, &token_a), deflationary)
+}
+
+#[test]
+fn test_swap_prices_fee_on_transfer_input_on_amount_received() {
+    let env = Env::default();
+    let (pair, _standard, deflationary) = setup_deflationary_pair(&env);
+
+    let trader = Address::generate(&env);
+    deflationary.mint(&trader, &1_000_000_000);
+
+    // Only 90% of the input arrives
+    deflationary.set_burn_bps(&1000);
+    let quoted = pair.get_amount_out(&90_000_000, &deflationary.address);
+    let amount_out = pair.swap(&trader, &100_000_000, &0, &deflationary.address, &u64::MAX);
+    assert_eq!(amount_out, quoted);
+
+    let (reserve_0, reserve_1, _) = pair.get_reserves();
+    let info = pair.get_pair_info();
+    let deflationary_reserve = if info.token_0 == deflationary.address { reserve_0 } else { reserve_1 };
+    assert_eq!(deflationary_reserve, 10_000_000_000 + 90_000_000);
+    assert_eq!(deflationary_reserve, deflationary.balance(&pair.address));
+}
+
+#[test]
+fn test_add_liquidity_credits_fee_on_transfer_amount_received() {
+    let env = Env::default();
+    let (pair, standard, deflationary) = setup_deflationary_pair(&env);
+
+    let provider = Address::generate(&env);
+    StellarAssetClient::new(&env, &standard.address).mint(&provider, &1_000_000_000);
+    deflationary.mint(&provider, &1_000_000_000);
+
+    deflationary.set_burn_bps(&1000);
+    let (amount_0, amount_1, liquidity) =
+        pair.add_liquidity(&provider, &1_000_000_000, &1_000_000_000, &0, &0, &u64::MAX);
+
+    // Reported and reserved amounts are what arrived; LP is minted on the short side
+    let info = pair.get_pair_info();
+    let (deflationary_amount, standard_amount) =
+        if info.token_0 == deflationary.address { (amount_0, amount_1) } else { (amount_1, amount_0) };
+    assert_eq!(deflationary_amount, 900_000_000);
+    assert_eq!(standard_amount, 1_000_000_000);
+    assert_eq!(liquidity, 900_000_000);
+
+    let (reserve_0, reserve_1, _) = pair.get_reserves();
+    assert_eq!(reserve_0, TokenClient::new(&env, &info.token_0).balance(&pair.address));
+    assert_eq!(reserve_1, TokenClient::new(&env, &info.token_1).balance(&pair.address));
+}
+
+#[test]
+#[should_panic(expected = "insufficient token")]
+fn test_add_liquidity_enforces_min_on_fee_on_transfer_amount_received() {
+    let env = Env::default();
+    let (pair, standard, deflationary) = setup_deflationary_pair(&env);
+
+    let provider = Address::generate(&env);
+    StellarAssetClient::new(&env, &standard.address).mint(&provider, &1_000_000_000);
+    deflationary.mint(&provider, &1_000_000_000);
+
+    // The pair's ratio matches the request exactly, but only 90% of the
+    // deflationary side arrives
+    deflationary.set_burn_bps(&1000);
+    pair.add_liquidity(&provider, &1_000_000_000, &1_000_000_000, &950_000_000, &950_000_000, &u64::MAX);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #43)")]
+fn test_swap_rejects_output_token_overdrawing_pair() {
+    let env = Env::default();
+    let (pair, standard, deflationary) = setup_deflationary_pair(&env);
+
+    let trader = Address::generate(&env);
+    StellarAssetClient::new(&env, &standard.address).mint(&trader, &1_000_000_000);
+
+    // Sending the output costs the pair 10% more than it priced: K drops
+    deflationary.set_surcharge_bps(&1000);
+    pair.swap(&trader, &100_000_000, &0, &standard.address, &u64::MAX);
+}
+
+#[test]
+fn test_swap_sets_reserves_to_actual_balances() {
+    let env = Env::default();
+    let (pair, token_0, token_1) = setup_pair(&env);
+
+    // A donation sitting in the pair is folded into the reserves by the next swap
+    donate(&env, &token_1, &pair.address, 50_000_000);
+
+    let trader = Address::generate(&env);
+    StellarAssetClient::new(&env, &token_0.address).mint(&trader, &1_000_000_000);
+    pair.swap(&trader, &100_000_000, &0, &token_0.address, &u64::MAX);
+
+    let (reserve_0, reserve_1, _) = pair.get_reserves();
+    assert_eq!(reserve_0, token_0.balance(&pair.address));
+    assert_eq!(reserve_1, token_1.balance(&pair.address));
+}
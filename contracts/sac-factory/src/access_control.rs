@@ -3,10 +3,13 @@
 //! Implements granular permission system for contract administration.
 //! Inspired by Aquarius AMM access control patterns.
 
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, Env, Vec};
 use crate::errors::Error;
 use crate::events;
 
+/// Maximum grants accepted by one `grant_roles` call
+pub const MAX_ROLE_GRANTS: u32 = 20;
+
 /// Available roles in the system
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq, Copy)]
@@ -23,6 +26,17 @@ pub enum Role {
     EmergencyPauser = 4,
 }
 
+impl Role {
+    /// Every role, in discriminant order (extend when adding a variant)
+    pub const ALL: [Role; 5] = [
+        Role::Owner,
+        Role::PauseAdmin,
+        Role::TreasuryAdmin,
+        Role::FeeAdmin,
+        Role::EmergencyPauser,
+    ];
+}
+
 /// Access control storage key
 #[derive(Clone)]
 #[contracttype]
@@ -36,6 +50,17 @@ pub fn has_role(env: &Env, account: &Address, role: Role) -> bool {
     env.storage().persistent().get(&key).unwrap_or(false)
 }
 
+/// List every role an address holds
+pub fn get_roles(env: &Env, account: &Address) -> Vec<Role> {
+    let mut roles = Vec::new(env);
+    for role in Role::ALL {
+        if has_role(env, account, role) {
+            roles.push_back(role);
+        }
+    }
+    roles
+}
+
 /// Require that an address has a specific role, panic otherwise
 pub fn require_role(env: &Env, account: &Address, role: Role) -> Result<(), Error> {
     if !has_role(env, account, role) {
@@ -61,6 +86,30 @@ pub fn grant_role(env: &Env, granter: &Address, account: &Address, role: Role) -
     Ok(())
 }
 
+/// Grant several roles in one call (only Owner can do this)
+///
+/// Checks run before any write, so the batch applies in full or not at all.
+/// Each grant emits its own `RoleGranted` event.
+pub fn grant_roles(env: &Env, granter: &Address, grants: &Vec<(Address, Role)>) -> Result<(), Error> {
+    granter.require_auth();
+
+    // Only Owner can grant roles
+    require_role(env, granter, Role::Owner)?;
+
+    if grants.len() > MAX_ROLE_GRANTS {
+        return Err(Error::BatchTooLarge);
+    }
+
+    for (account, role) in grants.iter() {
+        let key = AccessControlKey::Role(account.clone(), role);
+        env.storage().persistent().set(&key, &true);
+
+        events::role_granted(env, &account, role);
+    }
+
+    Ok(())
+}
+
 /// Revoke a role from an address (only Owner can do this)
 pub fn revoke_role(env: &Env, revoker: &Address, account: &Address, role: Role) -> Result<(), Error> {
     revoker.require_auth();
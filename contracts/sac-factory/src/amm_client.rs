@@ -23,6 +23,11 @@ impl<'a> AmmPairClient<'a> {
         Self { env, address }
     }
 
+    /// Address of the AMM pair contract
+    pub fn address(&self) -> &Address {
+        &self.address
+    }
+
     /// Initialize the AMM pair contract
     ///
     /// # Arguments
@@ -62,8 +67,8 @@ impl<'a> AmmPairClient<'a> {
     /// * `sender` - Address adding liquidity (factory)
     /// * `amount_0_desired` - Amount of token0 (XLM)
     /// * `amount_1_desired` - Amount of token1 (graduated token)
-    /// * `amount_0_min` - Minimum amount of token0 (slippage = 0 for initial)
-    /// * `amount_1_min` - Minimum amount of token1 (slippage = 0 for initial)
+    /// * `amount_0_min` - Minimum amount of token0 (equal to desired for the seed deposit)
+    /// * `amount_1_min` - Minimum amount of token1 (equal to desired for the seed deposit)
     /// * `deadline` - Transaction deadline
     ///
     /// # Returns
@@ -108,4 +113,21 @@ impl<'a> AmmPairClient<'a> {
 
         result.map_err(|_| Error::AmmInitializationFailed)
     }
+
+    /// Ensure the pair holds no reserves yet
+    ///
+    /// Graduation must be the first deposit; anything already in the pool
+    /// would set a ratio other than the curve's final price.
+    ///
+    /// # Returns
+    /// `Error::AmmPairNotEmpty` if either reserve is nonzero
+    pub fn require_empty(&self) -> Result<(), Error> {
+        let (reserve_0, reserve_1, _) = self.get_reserves()?;
+
+        if reserve_0 != 0 || reserve_1 != 0 {
+            return Err(Error::AmmPairNotEmpty);
+        }
+
+        Ok(())
+    }
 }
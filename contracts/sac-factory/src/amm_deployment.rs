@@ -12,7 +12,7 @@ use crate::storage::InstanceKey;
 ///
 /// # Arguments
 /// * `env` - Contract environment
-/// * `token_a` - First token address (typically XLM)
+/// * `token_a` - Quote asset of the curve (XLM, USDC, ...)
 /// * `token_b` - Second token address (graduated token)
 /// * `factory` - Factory contract address (this contract)
 /// * `fee_to` - Fee recipient address
@@ -60,6 +60,14 @@ pub fn deploy_amm_pair(
     Ok(deployed_address)
 }
 
+/// Whether the quote asset is the pair's `token_0`
+///
+/// The pair sorts its tokens by address, so amounts passed to or read from it
+/// must follow that order rather than (quote, token).
+pub fn quote_is_token_0(quote_asset: &Address, token: &Address) -> bool {
+    quote_asset < token
+}
+
 /// Calculate deterministic AMM pair address without deploying
 ///
 /// Useful for checking if a pair already exists before graduation
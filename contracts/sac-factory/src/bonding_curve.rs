@@ -8,9 +8,22 @@
 
 use soroban_sdk::contracttype;
 use crate::errors::Error;
+use crate::math;
 
 /// Precision for calculations
-const PRECISION: i128 = 10_000_000; // 7 decimals (Stellar standard)
+pub const PRECISION: i128 = 10_000_000; // 7 decimals (Stellar standard)
+
+/// Initial virtual XLM reserve (1000 XLM); sets the starting price and is the sell floor
+const INITIAL_VIRTUAL_XLM: i128 = 1000 * PRECISION;
+
+/// Largest curve supply whose `k` fits in i128 (~1.7e28 units, ~1.7e21 whole tokens)
+pub const MAX_CURVE_SUPPLY: i128 = i128::MAX / INITIAL_VIRTUAL_XLM;
+
+/// Max virtual-reserve decay (9000 bps: 10% of the virtual reserve remains at sell-out)
+pub const MAX_VIRTUAL_DECAY_BPS: i128 = 9000;
+
+/// Allowed drift of `xlm_reserve * tokens_remaining` from `k` (1/10_000 = 0.01%)
+const INVARIANT_TOLERANCE_DIVISOR: i128 = 10_000;
 
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -27,8 +40,11 @@ pub struct BondingCurve {
     /// XLM reserve in the curve
     pub xlm_reserve: i128,
 
-    /// Constant k (x * y = k)
+    /// Constant k (x * y = k); re-anchored after every trade when decay is on
     pub k: i128,
+
+    /// Share of the virtual XLM reserve removed by sell-out, in bps (0 = off)
+    pub virtual_decay_bps: i128,
 }
 
 impl BondingCurve {
@@ -38,18 +54,35 @@ impl BondingCurve {
     /// * `total_supply` - Total tokens allocated to bonding curve (800M)
     ///
     /// # Returns
-    /// Result with BondingCurve or Error if overflow occurs
+    /// Result with BondingCurve, or `Error::Overflow` above `MAX_CURVE_SUPPLY`
     pub fn new(total_supply: i128) -> Result<Self, Error> {
-        if total_supply <= 0 {
+        Self::new_with_decay(total_supply, 0)
+    }
+
+    /// Create new bonding curve whose virtual XLM reserve decays as tokens sell
+    ///
+    /// The virtual reserve shrinks linearly with `tokens_sold`, losing
+    /// `virtual_decay_bps` of `INITIAL_VIRTUAL_XLM` at sell-out. A thinner
+    /// reserve means each XLM moves the price further, so the ramp steepens
+    /// near graduation. See `effective_reserves`.
+    ///
+    /// # Returns
+    /// `Error::InvalidAmount` if `virtual_decay_bps` is outside 0-`MAX_VIRTUAL_DECAY_BPS`
+    pub fn new_with_decay(total_supply: i128, virtual_decay_bps: i128) -> Result<Self, Error> {
+        if total_supply <= 0 || !(0..=MAX_VIRTUAL_DECAY_BPS).contains(&virtual_decay_bps) {
             return Err(Error::InvalidAmount);
         }
+        if total_supply > MAX_CURVE_SUPPLY {
+            return Err(Error::Overflow);
+        }
 
         // Initial virtual XLM reserve (creates starting price)
-        // Starting with 1000 XLM virtual liquidity
-        let initial_xlm = 1000 * PRECISION;
+        let initial_xlm = INITIAL_VIRTUAL_XLM;
 
         // Calculate k constant with proper error handling
-        // k = x * y = xlm_reserve * tokens_remaining
+        // k = x * y = xlm_reserve * tokens_remaining (fits up to MAX_CURVE_SUPPLY;
+        // every later product of reserves goes through safe_mul_div or a
+        // rounded-up division, so trading never needs more headroom than k)
         let k = initial_xlm
             .checked_mul(total_supply)
             .ok_or(Error::Overflow)?;
@@ -60,33 +93,91 @@ impl BondingCurve {
             tokens_remaining: total_supply,
             xlm_reserve: initial_xlm,
             k,
+            virtual_decay_bps,
         })
     }
 
+    /// Placeholder curve for tokens launched straight to an AMM (`launch_to_amm`)
+    ///
+    /// No supply and no reserves, so nothing trades or prices against it.
+    pub fn empty() -> Self {
+        Self {
+            total_supply: 0,
+            tokens_sold: 0,
+            tokens_remaining: 0,
+            xlm_reserve: 0,
+            k: 0,
+            virtual_decay_bps: 0,
+        }
+    }
+
+    /// Whether this is the `empty` placeholder of a token that never had a curve
+    pub fn is_empty(&self) -> bool {
+        self.total_supply == 0
+    }
+
+    /// Virtual XLM still backing the price at the current `tokens_sold`
+    pub fn effective_virtual_xlm(&self) -> Result<i128, Error> {
+        if self.virtual_decay_bps == 0 {
+            return Ok(INITIAL_VIRTUAL_XLM);
+        }
+
+        let max_decay = math::apply_bps(INITIAL_VIRTUAL_XLM, self.virtual_decay_bps)?;
+        let decay = math::safe_mul_div(max_decay, self.tokens_sold, self.total_supply)?;
+        math::safe_sub(INITIAL_VIRTUAL_XLM, decay)
+    }
+
+    /// XLM reserve and k that pricing runs on
+    ///
+    /// Without decay this is `(xlm_reserve, k)`. With decay the virtual part of
+    /// `xlm_reserve` is swapped for `effective_virtual_xlm` and k is the product
+    /// of the adjusted reserve and `tokens_remaining`. Real XLM is untouched,
+    /// so the curve can always pay back every sell.
+    ///
+    /// # Returns
+    /// `(effective_xlm_reserve, effective_k)`
+    pub fn effective_reserves(&self) -> Result<(i128, i128), Error> {
+        if self.virtual_decay_bps == 0 {
+            return Ok((self.xlm_reserve, self.k));
+        }
+
+        let real_xlm = math::safe_sub(self.xlm_reserve, INITIAL_VIRTUAL_XLM)?;
+        let reserve = math::safe_add(real_xlm, self.effective_virtual_xlm()?)?;
+        let k = math::safe_mul(reserve, self.tokens_remaining)?;
+        Ok((reserve, k))
+    }
+
     /// Calculate tokens received for XLM input
     ///
-    /// Formula: tokens_out = tokens_remaining - (k / (xlm_reserve + xlm_in))
+    /// Formula: tokens_out = tokens_remaining - ceil(k / (xlm_reserve + xlm_in))
+    ///
+    /// Rounding: the new token reserve rounds up, so tokens out round down and
+    /// `xlm_reserve * tokens_remaining` never drops below `k`.
     pub fn calculate_buy(&self, xlm_in: i128) -> Result<i128, Error> {
         if xlm_in <= 0 {
             return Err(Error::InvalidAmount);
         }
 
+        let (xlm_reserve, k) = self.effective_reserves()?;
+
         // New XLM reserve after buy
-        let new_xlm_reserve = self.xlm_reserve
+        let new_xlm_reserve = xlm_reserve
             .checked_add(xlm_in)
             .ok_or(Error::Overflow)?;
 
-        // New token reserve (from k = x * y)
-        let new_token_reserve = self.k
-            .checked_div(new_xlm_reserve)
-            .ok_or(Error::DivisionByZero)?;
+        // New token reserve (from k = x * y), rounded up in the pool's favor
+        let new_token_reserve = math::safe_div_ceil(k, new_xlm_reserve)?;
 
         // Tokens out = old_reserve - new_reserve
         let tokens_out = self.tokens_remaining
             .checked_sub(new_token_reserve)
             .ok_or(Error::Underflow)?;
 
-        if tokens_out <= 0 {
+        // Dust input on a steep curve rounds down to zero tokens
+        if tokens_out == 0 {
+            return Err(Error::AmountTooSmall);
+        }
+        if tokens_out < 0 {
             return Err(Error::InsufficientLiquidity);
         }
 
@@ -95,7 +186,13 @@ impl BondingCurve {
 
     /// Calculate XLM received for token input
     ///
-    /// Formula: xlm_out = xlm_reserve - (k / (tokens_remaining + tokens_in))
+    /// Formula: xlm_out = xlm_reserve - ceil(k / (tokens_remaining + tokens_in))
+    ///
+    /// Rounding: the new XLM reserve rounds up, so XLM out rounds down and
+    /// `xlm_reserve * tokens_remaining` never drops below `k`.
+    ///
+    /// Fails with `InsufficientLiquidity` if the sell would take `xlm_reserve`
+    /// below the (effective) virtual reserve.
     pub fn calculate_sell(&self, tokens_in: i128) -> Result<i128, Error> {
         if tokens_in <= 0 {
             return Err(Error::InvalidAmount);
@@ -110,23 +207,68 @@ impl BondingCurve {
             .checked_add(tokens_in)
             .ok_or(Error::Overflow)?;
 
-        // New XLM reserve (from k = x * y)
-        let new_xlm_reserve = self.k
-            .checked_div(new_token_reserve)
-            .ok_or(Error::DivisionByZero)?;
+        let (xlm_reserve, k) = self.effective_reserves()?;
+
+        // New XLM reserve (from k = x * y), rounded up in the pool's favor
+        let new_xlm_reserve = math::safe_div_ceil(k, new_token_reserve)?;
+
+        // Never pay out the virtual liquidity
+        if new_xlm_reserve < self.effective_virtual_xlm()? {
+            return Err(Error::InsufficientLiquidity);
+        }
 
         // XLM out = old_reserve - new_reserve
-        let xlm_out = self.xlm_reserve
+        let xlm_out = xlm_reserve
             .checked_sub(new_xlm_reserve)
             .ok_or(Error::Underflow)?;
 
-        if xlm_out <= 0 {
+        // Dust input rounds down to zero XLM
+        if xlm_out == 0 {
+            return Err(Error::AmountTooSmall);
+        }
+        if xlm_out < 0 {
             return Err(Error::InsufficientLiquidity);
         }
 
         Ok(xlm_out)
     }
 
+    /// Calculate XLM required to receive an exact amount of tokens
+    ///
+    /// Formula: xlm_in = ceil(k / (tokens_remaining - tokens_out)) - xlm_reserve
+    ///
+    /// Rounds up so that `calculate_buy(xlm_in)` always yields at least `tokens_out`.
+    pub fn calculate_buy_exact_tokens(&self, tokens_out: i128) -> Result<i128, Error> {
+        if tokens_out <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        if tokens_out >= self.tokens_remaining {
+            return Err(Error::InsufficientLiquidity);
+        }
+
+        // New token reserve after buy
+        let new_token_reserve = self.tokens_remaining
+            .checked_sub(tokens_out)
+            .ok_or(Error::Underflow)?;
+
+        let (xlm_reserve, k) = self.effective_reserves()?;
+
+        // New XLM reserve (from k = x * y), rounded up
+        let new_xlm_reserve = math::safe_div_ceil(k, new_token_reserve)?;
+
+        // XLM in = new_reserve - old_reserve
+        let xlm_in = new_xlm_reserve
+            .checked_sub(xlm_reserve)
+            .ok_or(Error::Underflow)?;
+
+        if xlm_in <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        Ok(xlm_in)
+    }
+
     /// Execute buy (update state)
     pub fn execute_buy(&mut self, xlm_in: i128, tokens_out: i128) -> Result<(), Error> {
         // Update reserves
@@ -142,16 +284,24 @@ impl BondingCurve {
             .checked_add(tokens_out)
             .ok_or(Error::Overflow)?;
 
-        Ok(())
+        self.reanchor_k()?;
+        self.check_invariant()
     }
 
     /// Execute sell (update state)
+    ///
+    /// Fails with `InsufficientLiquidity` if `xlm_reserve` would drop below the
+    /// initial virtual reserve.
     pub fn execute_sell(&mut self, xlm_out: i128, tokens_in: i128) -> Result<(), Error> {
         // Update reserves
         self.xlm_reserve = self.xlm_reserve
             .checked_sub(xlm_out)
             .ok_or(Error::Underflow)?;
 
+        if self.xlm_reserve < INITIAL_VIRTUAL_XLM {
+            return Err(Error::InsufficientLiquidity);
+        }
+
         self.tokens_remaining = self.tokens_remaining
             .checked_add(tokens_in)
             .ok_or(Error::Overflow)?;
@@ -160,21 +310,126 @@ impl BondingCurve {
             .checked_sub(tokens_in)
             .ok_or(Error::Underflow)?;
 
+        self.reanchor_k()?;
+        self.check_invariant()
+    }
+
+    /// Reset k to the current product on a decaying curve
+    ///
+    /// Decayed trades price off `effective_reserves`, so the stored k no longer
+    /// describes the curve; anchoring it to the new state keeps
+    /// `check_invariant` meaningful for the next trade.
+    fn reanchor_k(&mut self) -> Result<(), Error> {
+        if self.virtual_decay_bps != 0 {
+            self.k = math::safe_mul(self.xlm_reserve, self.tokens_remaining)?;
+        }
+        Ok(())
+    }
+
+    /// Verify the constant product invariant still holds
+    ///
+    /// Checks that reserves are non-negative and that `xlm_reserve * tokens_remaining`
+    /// stays within a rounding tolerance of `k`.
+    ///
+    /// # Returns
+    /// `Error::InvalidState` if the curve state is inconsistent
+    pub fn check_invariant(&self) -> Result<(), Error> {
+        if self.xlm_reserve <= 0 || self.tokens_remaining < 0 || self.tokens_sold < 0 {
+            return Err(Error::InvalidState);
+        }
+
+        let product = self.xlm_reserve
+            .checked_mul(self.tokens_remaining)
+            .ok_or(Error::InvalidState)?;
+
+        let drift = self.k
+            .checked_sub(product)
+            .ok_or(Error::InvalidState)?
+            .abs();
+
+        if drift > self.k / INVARIANT_TOLERANCE_DIVISOR {
+            return Err(Error::InvalidState);
+        }
+
         Ok(())
     }
 
     /// Get current price per token (in stroops)
+    ///
+    /// Uses the effective XLM reserve, so decay is reflected. Computed in stages (whole part, then remainder) so large reserves don't
+    /// overflow `xlm_reserve * PRECISION`. Saturates at `i128::MAX` only when the
+    /// price itself doesn't fit (or no tokens remain).
     pub fn get_current_price(&self) -> i128 {
-        if self.tokens_remaining == 0 {
+        if self.tokens_remaining <= 0 {
             return i128::MAX;
         }
 
-        // Price = xlm_reserve / tokens_remaining
-        self.xlm_reserve
+        let xlm_reserve = self.effective_reserves()
+            .map(|(reserve, _)| reserve)
+            .unwrap_or(self.xlm_reserve);
+
+        // Price = xlm_reserve * PRECISION / tokens_remaining
+        //       = (q * PRECISION) + (r * PRECISION / tokens_remaining)
+        let q = xlm_reserve / self.tokens_remaining;
+        let r = xlm_reserve % self.tokens_remaining;
+
+        let whole = q.checked_mul(PRECISION);
+        let fraction = r
             .checked_mul(PRECISION)
-            .unwrap_or(i128::MAX)
-            .checked_div(self.tokens_remaining)
-            .unwrap_or(i128::MAX)
+            .map(|v| v / self.tokens_remaining);
+
+        match (whole, fraction) {
+            (Some(whole), Some(fraction)) => whole.checked_add(fraction).unwrap_or(i128::MAX),
+            _ => i128::MAX,
+        }
+    }
+
+    /// Size the AMM seed so its opening price equals `get_current_price`
+    ///
+    /// Pairs all `real_xlm` with `real_xlm * tokens_remaining / xlm_reserve` tokens.
+    /// If that needs more than `max_tokens`, all `max_tokens` are used and the XLM
+    /// side is scaled down instead. Either way only one rounding step separates
+    /// the two prices.
+    ///
+    /// # Returns
+    /// `(xlm_liquidity, token_liquidity)`; the caller disposes of the remainder
+    pub fn graduation_liquidity(&self, real_xlm: i128, max_tokens: i128) -> Result<(i128, i128), Error> {
+        if real_xlm < 0 || max_tokens < 0 || self.tokens_remaining <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let (xlm_reserve, _) = self.effective_reserves()?;
+
+        let tokens = math::safe_mul_div(real_xlm, self.tokens_remaining, xlm_reserve)?;
+        if tokens <= max_tokens {
+            return Ok((real_xlm, tokens));
+        }
+
+        let xlm = math::safe_mul_div(max_tokens, xlm_reserve, self.tokens_remaining)?;
+        Ok((xlm.min(real_xlm), max_tokens))
+    }
+
+    /// Check that an AMM seed opens within `tolerance_bps` of `get_current_price`
+    ///
+    /// A wider gap means the locked liquidity could be arbitraged immediately,
+    /// which points to an accounting bug in the seed amounts.
+    ///
+    /// # Returns
+    /// `Error::GraduationPriceMismatch` if the seed price is outside the tolerance
+    pub fn check_seed_price(&self, xlm_liquidity: i128, token_liquidity: i128, tolerance_bps: i128) -> Result<(), Error> {
+        if xlm_liquidity <= 0 || token_liquidity <= 0 {
+            return Err(Error::GraduationPriceMismatch);
+        }
+
+        let spot_price = self.get_current_price();
+        let seed_price = math::mul_div(xlm_liquidity, PRECISION, token_liquidity)?;
+        let gap = math::safe_sub(seed_price, spot_price)?.abs();
+
+        if gap > math::apply_bps(spot_price, tolerance_bps)? {
+            return Err(Error::GraduationPriceMismatch);
+        }
+
+        Ok(())
     }
 
     /// Get market cap (total value)
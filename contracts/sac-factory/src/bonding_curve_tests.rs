@@ -3,7 +3,7 @@
 
 #[cfg(test)]
 mod bonding_curve_tests {
-    use crate::bonding_curve::BondingCurve;
+    use crate::bonding_curve::{BondingCurve, MAX_CURVE_SUPPLY};
     use crate::errors::Error;
 
     const BONDING_CURVE_SUPPLY: i128 = 800_000_000_0000000; // 800M tokens
@@ -94,6 +94,78 @@ mod bonding_curve_tests {
         assert!(tokens_second < tokens_first, "Second buy should get fewer tokens");
     }
 
+    #[test]
+    fn test_calculate_buy_exact_tokens_inverts_buy() {
+        let curve = BondingCurve::new(BONDING_CURVE_SUPPLY).unwrap();
+        let tokens_desired = 50_000_000_000_000; // 5M tokens
+
+        let xlm_required = curve.calculate_buy_exact_tokens(tokens_desired).unwrap();
+        let tokens_out = curve.calculate_buy(xlm_required).unwrap();
+
+        // Rounding up XLM guarantees at least the desired amount
+        assert!(tokens_out >= tokens_desired);
+
+        // One stroop less never overshoots the desired amount
+        let tokens_short = curve.calculate_buy(xlm_required - 1).unwrap();
+        assert!(tokens_short <= tokens_desired);
+    }
+
+    #[test]
+    fn test_calculate_buy_exact_tokens_exceeds_remaining_fails() {
+        let curve = BondingCurve::new(BONDING_CURVE_SUPPLY).unwrap();
+        let result = curve.calculate_buy_exact_tokens(BONDING_CURVE_SUPPLY);
+
+        assert_eq!(result.unwrap_err(), Error::InsufficientLiquidity);
+    }
+
+    #[test]
+    fn test_dust_buy_on_steep_curve_fails() {
+        // Nearly sold-out curve: each token costs far more than one stroop.
+        // k carries the rounding remainder left behind by earlier trades.
+        let tokens_remaining = 10;
+        let xlm_reserve = 100_000_000_000; // 10,000 XLM
+        let curve = BondingCurve {
+            total_supply: BONDING_CURVE_SUPPLY,
+            tokens_sold: BONDING_CURVE_SUPPLY - tokens_remaining,
+            tokens_remaining,
+            xlm_reserve,
+            k: xlm_reserve * tokens_remaining + tokens_remaining,
+            virtual_decay_bps: 0,
+        };
+
+        assert_eq!(curve.calculate_buy(1).unwrap_err(), Error::AmountTooSmall);
+
+        // Enough XLM for a whole token still works
+        assert!(curve.calculate_buy(xlm_reserve).unwrap() > 0);
+    }
+
+    #[test]
+    fn test_get_current_price_large_reserve_does_not_saturate() {
+        // xlm_reserve * PRECISION would overflow i128
+        let tokens_remaining = 10_000_000_000_000_000; // 1B tokens
+        let xlm_reserve = 10_i128.pow(33);
+        let curve = BondingCurve {
+            total_supply: tokens_remaining,
+            tokens_sold: 0,
+            tokens_remaining,
+            xlm_reserve,
+            k: i128::MAX,
+            virtual_decay_bps: 0,
+        };
+
+        // 10^33 / 10^16 = 10^17 stroops per token, scaled by PRECISION (10^7)
+        assert_eq!(curve.get_current_price(), 10_i128.pow(24));
+    }
+
+    #[test]
+    fn test_get_current_price_matches_direct_formula() {
+        let curve = BondingCurve::new(BONDING_CURVE_SUPPLY).unwrap();
+        assert_eq!(
+            curve.get_current_price(),
+            curve.xlm_reserve * 10_000_000 / curve.tokens_remaining
+        );
+    }
+
     // ========== Sell Calculation Tests ==========
 
     #[test]
@@ -224,6 +296,44 @@ mod bonding_curve_tests {
         assert!(k_diff < tolerance);
     }
 
+    // ========== Invariant Tests ==========
+
+    #[test]
+    fn test_check_invariant_holds_after_trades() {
+        let mut curve = BondingCurve::new(BONDING_CURVE_SUPPLY).unwrap();
+        assert!(curve.check_invariant().is_ok());
+
+        let xlm_in = 500_0000000;
+        let tokens_out = curve.calculate_buy(xlm_in).unwrap();
+        curve.execute_buy(xlm_in, tokens_out).unwrap();
+        assert!(curve.check_invariant().is_ok());
+
+        let xlm_out = curve.calculate_sell(tokens_out / 3).unwrap();
+        curve.execute_sell(xlm_out, tokens_out / 3).unwrap();
+        assert!(curve.check_invariant().is_ok());
+    }
+
+    #[test]
+    fn test_check_invariant_detects_corrupted_curve() {
+        let mut curve = BondingCurve::new(BONDING_CURVE_SUPPLY).unwrap();
+
+        // Inflate the XLM reserve without moving tokens
+        curve.xlm_reserve *= 2;
+
+        assert_eq!(curve.check_invariant().unwrap_err(), Error::InvalidState);
+    }
+
+    #[test]
+    fn test_execute_buy_rejects_inconsistent_amounts() {
+        let mut curve = BondingCurve::new(BONDING_CURVE_SUPPLY).unwrap();
+        let xlm_in = 100_0000000;
+        let tokens_out = curve.calculate_buy(xlm_in).unwrap();
+
+        // Claiming twice the tokens the curve would give breaks k
+        let result = curve.execute_buy(xlm_in, tokens_out * 2);
+        assert_eq!(result.unwrap_err(), Error::InvalidState);
+    }
+
     // ========== Price Tests ==========
 
     #[test]
@@ -264,7 +374,7 @@ mod bonding_curve_tests {
         // Sell
         let tokens_to_sell = tokens_bought / 2;
         let xlm_out = curve.calculate_sell(tokens_to_sell).unwrap();
-        curve.execute_sell(tokens_to_sell, xlm_out).unwrap();
+        curve.execute_sell(xlm_out, tokens_to_sell).unwrap(); // Fixed parameter order
 
         let price_after_sell = curve.get_current_price();
 
@@ -330,6 +440,59 @@ mod bonding_curve_tests {
         assert!(diff <= tolerance, "Roundtrip diff {} exceeds tolerance {}", diff, tolerance);
     }
 
+    #[test]
+    fn test_full_sell_round_trip_returns_to_virtual_floor() {
+        let mut curve = BondingCurve::new(BONDING_CURVE_SUPPLY).unwrap();
+        let initial_reserve = curve.xlm_reserve;
+
+        // Dev buy followed by a second buyer
+        let dev_xlm = 500_0000000;
+        let dev_tokens = curve.calculate_buy(dev_xlm).unwrap();
+        curve.execute_buy(dev_xlm, dev_tokens).unwrap();
+
+        let buyer_xlm = 2000_0000000;
+        let buyer_tokens = curve.calculate_buy(buyer_xlm).unwrap();
+        curve.execute_buy(buyer_xlm, buyer_tokens).unwrap();
+
+        // Both sell everything
+        let xlm_out = curve.calculate_sell(buyer_tokens).unwrap();
+        curve.execute_sell(xlm_out, buyer_tokens).unwrap();
+        let xlm_out = curve.calculate_sell(dev_tokens).unwrap();
+        curve.execute_sell(xlm_out, dev_tokens).unwrap();
+
+        // Curve is empty again and sits exactly on the virtual floor
+        assert_eq!(curve.tokens_sold, 0);
+        assert_eq!(curve.tokens_remaining, BONDING_CURVE_SUPPLY);
+        assert_eq!(curve.xlm_reserve, initial_reserve);
+        assert!(curve.check_invariant().is_ok());
+    }
+
+    #[test]
+    fn test_sell_below_virtual_floor_fails() {
+        let mut curve = BondingCurve::new(BONDING_CURVE_SUPPLY).unwrap();
+        let initial_reserve = curve.xlm_reserve;
+
+        let xlm_in = 100_0000000;
+        let tokens_out = curve.calculate_buy(xlm_in).unwrap();
+        curve.execute_buy(xlm_in, tokens_out).unwrap();
+
+        // Paying out more than was deposited would dip into virtual liquidity
+        let result = curve.execute_sell(xlm_in + 1, tokens_out);
+        assert_eq!(result, Err(Error::InsufficientLiquidity));
+
+        // A drifted k must not let a quote dip below the floor either
+        // (drift by a full stroop of reserve, since reserves round up)
+        let drifted = BondingCurve {
+            total_supply: BONDING_CURVE_SUPPLY,
+            tokens_sold: tokens_out,
+            tokens_remaining: BONDING_CURVE_SUPPLY - tokens_out,
+            xlm_reserve: initial_reserve + xlm_in,
+            k: curve.k - BONDING_CURVE_SUPPLY,
+            virtual_decay_bps: 0,
+        };
+        assert_eq!(drifted.calculate_sell(tokens_out), Err(Error::InsufficientLiquidity));
+    }
+
     #[test]
     fn test_large_buy_high_slippage() {
         let curve = BondingCurve::new(BONDING_CURVE_SUPPLY).unwrap();
@@ -347,4 +510,237 @@ mod bonding_curve_tests {
         let expected_if_no_slippage = small_tokens * 100;
         assert!(large_tokens < expected_if_no_slippage, "Large buy should have slippage");
     }
+
+    // ========== Rounding Tests ==========
+
+    #[test]
+    fn test_rounding_favors_pool() {
+        let mut curve = BondingCurve::new(BONDING_CURVE_SUPPLY).unwrap();
+
+        // Buy: the exact output is k / x - k / (x + dx); the quote never exceeds it
+        let xlm_in = 3_3333333;
+        let tokens_out = curve.calculate_buy(xlm_in).unwrap();
+        let new_xlm_reserve = curve.xlm_reserve + xlm_in;
+        assert!(tokens_out * new_xlm_reserve <= curve.tokens_remaining * new_xlm_reserve - curve.k);
+        curve.execute_buy(xlm_in, tokens_out).unwrap();
+        assert!(curve.xlm_reserve * curve.tokens_remaining >= curve.k);
+
+        // Sell: the exact output is x - k / (y + dy); the quote never exceeds it
+        let tokens_in = tokens_out / 3;
+        let xlm_out = curve.calculate_sell(tokens_in).unwrap();
+        let new_token_reserve = curve.tokens_remaining + tokens_in;
+        assert!(xlm_out * new_token_reserve <= curve.xlm_reserve * new_token_reserve - curve.k);
+        curve.execute_sell(xlm_out, tokens_in).unwrap();
+        assert!(curve.xlm_reserve * curve.tokens_remaining >= curve.k);
+    }
+
+    #[test]
+    fn test_randomized_round_trips_never_drain_pool() {
+        let mut curve = BondingCurve::new(BONDING_CURVE_SUPPLY).unwrap();
+        let initial_reserve = curve.xlm_reserve;
+        let mut xlm_paid_in: i128 = 0;
+        let mut xlm_paid_out: i128 = 0;
+
+        // Deterministic LCG so failures are reproducible
+        let mut seed: u64 = 0x5eed_1234_abcd_0001;
+        let mut next = |bound: i128| -> i128 {
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+            ((seed >> 33) as i128) % bound + 1
+        };
+
+        for _ in 0..2_000 {
+            // Buy between 1 stroop and ~500 XLM, then sell back a random part
+            let xlm_in = next(5_000_000_000);
+            let tokens_out = match curve.calculate_buy(xlm_in) {
+                Ok(tokens_out) => tokens_out,
+                Err(Error::AmountTooSmall) => continue,
+                Err(e) => panic!("unexpected buy error: {:?}", e),
+            };
+            curve.execute_buy(xlm_in, tokens_out).unwrap();
+            xlm_paid_in += xlm_in;
+
+            let tokens_in = next(curve.tokens_sold);
+            if let Ok(xlm_out) = curve.calculate_sell(tokens_in) {
+                curve.execute_sell(xlm_out, tokens_in).unwrap();
+                xlm_paid_out += xlm_out;
+            }
+
+            assert!(curve.xlm_reserve * curve.tokens_remaining >= curve.k);
+            assert_eq!(curve.xlm_reserve - initial_reserve, xlm_paid_in - xlm_paid_out);
+        }
+
+        // Selling everything back can't return more than was paid in
+        if curve.tokens_sold > 0 {
+            if let Ok(xlm_out) = curve.calculate_sell(curve.tokens_sold) {
+                xlm_paid_out += xlm_out;
+            }
+        }
+        assert!(xlm_paid_out <= xlm_paid_in);
+    }
+
+    // ========== Graduation Liquidity Tests ==========
+
+    #[test]
+    fn test_graduation_liquidity_matches_final_price() {
+        let mut curve = BondingCurve::new(BONDING_CURVE_SUPPLY).unwrap();
+        let xlm_raised = 100_000_000_000; // 10k XLM
+        let tokens_out = curve.calculate_buy(xlm_raised).unwrap();
+        curve.execute_buy(xlm_raised, tokens_out).unwrap();
+
+        let (xlm, tokens) = curve
+            .graduation_liquidity(xlm_raised, curve.tokens_remaining)
+            .unwrap();
+        assert_eq!(xlm, xlm_raised);
+        assert!(tokens < curve.tokens_remaining, "Excess tokens are held back");
+
+        // AMM opening price within 0.01% of the curve's final price
+        let amm_price = xlm * 10_000_000 / tokens;
+        let curve_price = curve.get_current_price();
+        assert!((amm_price - curve_price).abs() <= curve_price / 10_000);
+    }
+
+    #[test]
+    fn test_graduation_liquidity_scales_xlm_when_tokens_short() {
+        let mut curve = BondingCurve::new(BONDING_CURVE_SUPPLY).unwrap();
+        let xlm_raised = 100_000_000_000; // 10k XLM
+        let tokens_out = curve.calculate_buy(xlm_raised).unwrap();
+        curve.execute_buy(xlm_raised, tokens_out).unwrap();
+
+        // A large creator carve-out leaves fewer tokens than the XLM needs
+        let max_tokens = curve.tokens_remaining / 2;
+        let (xlm, tokens) = curve.graduation_liquidity(xlm_raised, max_tokens).unwrap();
+        assert_eq!(tokens, max_tokens);
+        assert!(xlm < xlm_raised);
+
+        let amm_price = xlm * 10_000_000 / tokens;
+        let curve_price = curve.get_current_price();
+        assert!((amm_price - curve_price).abs() <= curve_price / 10_000);
+    }
+
+    #[test]
+    fn test_check_seed_price_rejects_ratio_mismatch() {
+        let mut curve = BondingCurve::new(BONDING_CURVE_SUPPLY).unwrap();
+        let xlm_raised = 100_000_000_000; // 10k XLM
+        let tokens_out = curve.calculate_buy(xlm_raised).unwrap();
+        curve.execute_buy(xlm_raised, tokens_out).unwrap();
+
+        // The seed graduation would use passes
+        let (xlm, tokens) = curve
+            .graduation_liquidity(xlm_raised, curve.tokens_remaining)
+            .unwrap();
+        assert!(curve.check_seed_price(xlm, tokens, 200).is_ok());
+
+        // 5% too few tokens opens the pool ~5% above the curve
+        let skewed = tokens - tokens / 20;
+        assert_eq!(curve.check_seed_price(xlm, skewed, 200), Err(Error::GraduationPriceMismatch));
+        assert!(curve.check_seed_price(xlm, skewed, 600).is_ok());
+
+        // And the other way: 5% too many tokens
+        let skewed = tokens + tokens / 20;
+        assert_eq!(curve.check_seed_price(xlm, skewed, 200), Err(Error::GraduationPriceMismatch));
+
+        assert_eq!(curve.check_seed_price(xlm, 0, 200), Err(Error::GraduationPriceMismatch));
+    }
+
+    // ========== Large Supply Tests ==========
+
+    #[test]
+    fn test_max_curve_supply_creates_without_overflow() {
+        let curve = BondingCurve::new(MAX_CURVE_SUPPLY).unwrap();
+        assert_eq!(curve.tokens_remaining, MAX_CURVE_SUPPLY);
+        assert!(curve.check_invariant().is_ok());
+
+        // One unit more and k no longer fits
+        assert_eq!(BondingCurve::new(MAX_CURVE_SUPPLY + 1).unwrap_err(), Error::Overflow);
+    }
+
+    #[test]
+    fn test_large_supply_curve_trades_and_graduates() {
+        // Far above any launchable supply, with headroom for rounding up
+        let supply = MAX_CURVE_SUPPLY / 2;
+        let mut curve = BondingCurve::new(supply).unwrap();
+
+        let xlm_in = 100_000_000_000; // 10k XLM
+        let tokens_out = curve.calculate_buy(xlm_in).unwrap();
+        curve.execute_buy(xlm_in, tokens_out).unwrap();
+
+        // Exact-out quoting rounds up without adding to k
+        let exact_in = curve.calculate_buy_exact_tokens(curve.tokens_remaining / 10).unwrap();
+        assert!(exact_in > 0);
+
+        // real_xlm * tokens_remaining overflows i128 here; sizing still works
+        let (xlm, tokens) = curve
+            .graduation_liquidity(xlm_in, curve.tokens_remaining)
+            .unwrap();
+        assert_eq!(xlm, xlm_in);
+        assert!(curve.check_seed_price(xlm, tokens, 200).is_ok());
+
+        let xlm_out = curve.calculate_sell(tokens_out / 2).unwrap();
+        curve.execute_sell(xlm_out, tokens_out / 2).unwrap();
+        assert!(curve.check_invariant().is_ok());
+    }
+
+    // ========== Virtual Reserve Decay Tests ==========
+
+    /// Buy `steps` chunks of `xlm_per_step`, returning the spot price before and
+    /// after the last chunk
+    fn last_step_prices(curve: &mut BondingCurve, xlm_per_step: i128, steps: u32) -> (i128, i128) {
+        let mut before = curve.get_current_price();
+        for _ in 0..steps {
+            before = curve.get_current_price();
+            let tokens_out = curve.calculate_buy(xlm_per_step).unwrap();
+            curve.execute_buy(xlm_per_step, tokens_out).unwrap();
+        }
+        (before, curve.get_current_price())
+    }
+
+    #[test]
+    fn test_virtual_decay_steepens_late_stage_ramp() {
+        let chunk = 10_000_000_000; // 1,000 XLM, ten chunks reach graduation
+        let mut flat = BondingCurve::new(BONDING_CURVE_SUPPLY).unwrap();
+        let mut decayed = BondingCurve::new_with_decay(BONDING_CURVE_SUPPLY, 5000).unwrap();
+
+        // Same opening price: nothing has decayed before the first sale
+        assert_eq!(flat.get_current_price(), decayed.get_current_price());
+
+        let (flat_before, flat_after) = last_step_prices(&mut flat, chunk, 10);
+        let (decayed_before, decayed_after) = last_step_prices(&mut decayed, chunk, 10);
+
+        // The final 1,000 XLM moves the price further with decay on
+        let flat_ramp_bps = (flat_after - flat_before) * 10_000 / flat_before;
+        let decayed_ramp_bps = (decayed_after - decayed_before) * 10_000 / decayed_before;
+        assert!(decayed_ramp_bps > flat_ramp_bps);
+
+        // The same XLM also clears more of the curve
+        assert!(decayed.tokens_sold > flat.tokens_sold);
+        assert!(decayed.effective_virtual_xlm().unwrap() < flat.effective_virtual_xlm().unwrap());
+        assert!(decayed.check_invariant().is_ok());
+    }
+
+    #[test]
+    fn test_virtual_decay_round_trip_is_not_profitable() {
+        let mut curve = BondingCurve::new_with_decay(BONDING_CURVE_SUPPLY, 9000).unwrap();
+        let xlm_in = 50_000_000_000; // 5,000 XLM
+        let tokens_out = curve.calculate_buy(xlm_in).unwrap();
+        curve.execute_buy(xlm_in, tokens_out).unwrap();
+
+        // Selling straight back returns less than was paid, never the virtual XLM
+        let xlm_out = curve.calculate_sell(tokens_out).unwrap();
+        assert!(xlm_out < xlm_in);
+        curve.execute_sell(xlm_out, tokens_out).unwrap();
+        assert!(curve.xlm_reserve >= BondingCurve::new(BONDING_CURVE_SUPPLY).unwrap().xlm_reserve);
+    }
+
+    #[test]
+    fn test_virtual_decay_out_of_range_fails() {
+        assert_eq!(
+            BondingCurve::new_with_decay(BONDING_CURVE_SUPPLY, 9001).unwrap_err(),
+            Error::InvalidAmount
+        );
+        assert_eq!(
+            BondingCurve::new_with_decay(BONDING_CURVE_SUPPLY, -1).unwrap_err(),
+            Error::InvalidAmount
+        );
+        assert_eq!(BondingCurve::new_with_decay(BONDING_CURVE_SUPPLY, 0).unwrap().virtual_decay_bps, 0);
+    }
 }
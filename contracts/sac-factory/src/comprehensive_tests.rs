@@ -25,7 +25,7 @@ mod comprehensive_tests {
     }
 
     fn get_test_deadline(env: &Env) -> u64 {
-        env.ledger().timestamp() + 31_536_000
+        env.ledger().timestamp() + 3600
     }
 
     // ========== Initialization Edge Cases ==========
@@ -251,7 +251,30 @@ mod comprehensive_tests {
         // Verify
         let fee_config = client.get_fee_config();
         assert_eq!(fee_config.creation_fee, new_creation_fee);
-        assert_eq!(fee_config.trading_fee_bps, new_trading_fee);
+        assert_eq!(fee_config.buy_fee_bps, new_trading_fee);
+        assert_eq!(fee_config.sell_fee_bps, new_trading_fee);
+    }
+
+    #[test]
+    fn test_update_fees_at_cap() {
+        let env = Env::default();
+        let (client, admin, _treasury) = setup_factory(&env);
+        env.mock_all_auths();
+
+        // 10% is the highest allowed trading fee
+        client.update_fees(&admin, &100_000, &1000);
+        assert_eq!(client.get_fee_config().buy_fee_bps, 1000);
+        assert_eq!(client.get_fee_config().sell_fee_bps, 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #81)")]
+    fn test_update_fees_above_cap_fails() {
+        let env = Env::default();
+        let (client, admin, _treasury) = setup_factory(&env);
+        env.mock_all_auths();
+
+        client.update_fees(&admin, &100_000, &1001);
     }
 
     #[test]
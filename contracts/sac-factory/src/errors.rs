@@ -12,21 +12,28 @@ pub enum Error {
 
     // Authorization
     Unauthorized = 10,
-    NotAdmin = 11,
+    NotWhitelisted = 12, // Launch whitelist is on and the creator isn't on it
 
     // Validation
     InvalidName = 20,
     InvalidSymbol = 21,
     InvalidAmount = 22,
+    InvalidMetadata = 23,
+    InvalidQuoteAsset = 24,
 
     // Token state
     TokenNotFound = 30,
-    AlreadyGraduated = 31,
+    AlreadyGraduated = 31, // Curve closed; trade on the AMM pair (get_post_graduation_venue)
     InsufficientLiquidity = 32,
+    NotGraduated = 33,
 
     // Trading
     SlippageExceeded = 40,
     InsufficientBalance = 41,
+    TradingNotStarted = 42,
+    SellsDisabled = 43,
+    AmountTooSmall = 44,
+    DailyLimitExceeded = 45,
 
     // Math
     Overflow = 50,
@@ -36,6 +43,7 @@ pub enum Error {
     // Access Control
     CannotRevokeOwnOwnership = 60,
     RoleNotFound = 61,
+    BatchTooLarge = 62,
 
     // State Management
     ContractPaused = 70,
@@ -46,6 +54,8 @@ pub enum Error {
     InsufficientFee = 80,
     FeeTooHigh = 81,
     InvalidFeeConfiguration = 82,
+    WithdrawalTimelocked = 83,
+    NoPendingWithdrawal = 84,
 
     // Deployment
     DeploymentFailed = 90,
@@ -56,10 +66,13 @@ pub enum Error {
     // Transaction Protection
     TransactionExpired = 100,
     TransferFailed = 101,
+    DeadlineTooFar = 102,
 
     // AMM / Graduation
     AmmInitializationFailed = 110,
     InsufficientLiquidityForGraduation = 111,
+    AmmPairNotEmpty = 112,
+    GraduationPriceMismatch = 113,
 
     // Price Oracle
     OracleNotConfigured = 120,
@@ -2,7 +2,7 @@
 //!
 //! Using modern #[contractevent] macro for type-safe event emission
 
-use soroban_sdk::{contractevent, Address, Env, String};
+use soroban_sdk::{contractevent, Address, Env, String, Vec};
 use crate::access_control::Role;
 
 /// Token launched event
@@ -138,11 +138,17 @@ pub struct TokenLaunchedDetailed {
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TokensBoughtDetailed {
+    /// Receives the tokens
     pub buyer: Address,
+    /// Funded the buy (same as `buyer` unless bought via `buy_for`)
+    pub payer: Address,
     pub token: Address,
     pub xlm_amount: i128,
     pub tokens_gross: i128,
+    /// Trading fee (in XLM when `fee_in_xlm` is set, otherwise in tokens)
     pub fee_amount: i128,
+    /// Buy fee rate applied (0 when the fee rounded to nothing)
+    pub fee_bps: i128,
     pub tokens_net: i128,
     pub price_before: i128,
     pub price_after: i128,
@@ -150,15 +156,114 @@ pub struct TokensBoughtDetailed {
     pub timestamp: u64,
 }
 
+/// Enhanced tokens sold event with fee info
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokensSoldDetailed {
+    pub seller: Address,
+    pub token: Address,
+    pub tokens_sold: i128,
+    pub xlm_gross: i128,
+    /// Trading fee (always in the quote asset)
+    pub fee_amount: i128,
+    /// Sell fee rate applied (0 when the fee rounded to nothing)
+    pub fee_bps: i128,
+    pub xlm_net: i128,
+    pub price_before: i128,
+    pub price_after: i128,
+    pub timestamp: u64,
+}
+
 /// Graduation event with AMM details
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct GraduationDetailed {
     pub token: Address,
     pub xlm_raised: i128,
+    /// Graduated tokens the pair reports as deposited
     pub tokens_graduated: i128,
     pub amm_pair_address: Address,
+    /// LP tokens the pair reports as minted (locked in the factory for good)
     pub lp_tokens_burned: i128,
+    pub creator_allocation: i128,
+    /// Raised XLM sent to the treasury instead of the pool
+    pub graduation_fee: i128,
+    /// Payer of the buy that crossed the threshold (the creator for `launch_to_amm`)
+    pub graduated_by: Address,
+    pub timestamp: u64,
+}
+
+/// Creator claimed graduation token allocation
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorAllocationClaimed {
+    pub creator: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Creator toggled sells on a bonding curve token
+///
+/// Emitted on every toggle so UIs can warn buyers about sell pauses.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SellsToggled {
+    pub token: Address,
+    pub creator: Address,
+    pub enabled: bool,
+    /// Timestamp sells resume at the latest (0 when enabled)
+    pub resumes_at: u64,
+}
+
+/// Token image/description set at launch or changed by the creator
+///
+/// Indexers can mirror current metadata from this event stream alone.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataUpdated {
+    pub token: Address,
+    pub image_url: String,
+    pub description: String,
+    pub updated_by: Address,
+}
+
+/// Token creator handed the project to a new address
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenOwnershipTransferred {
+    pub token: Address,
+    pub previous_creator: Address,
+    pub new_creator: Address,
+}
+
+/// Residual graduated-token balance swept to treasury
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenSwept {
+    pub token: Address,
+    pub treasury: Address,
+    pub amount: i128,
+    pub swept_by: Address,
+}
+
+/// Part of a token-denominated trading fee burned
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeBurned {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Factory initialized; the first event of every deployment
+///
+/// Records the genesis configuration so indexers need not read storage.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Initialized {
+    pub admin: Address,
+    pub treasury: Address,
+    pub creation_fee: i128,
+    pub trading_fee_bps: i128,
     pub timestamp: u64,
 }
 
@@ -199,7 +304,49 @@ pub struct ContractUnpaused {
     pub timestamp: u64,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TradingPauseUpdated {
+    pub paused: bool,
+    pub updated_by: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LaunchesPauseUpdated {
+    pub paused: bool,
+    pub updated_by: Address,
+}
+
 /// Fee configuration events
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeDirectionUpdated {
+    pub fee_in_xlm: bool,
+    pub updated_by: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeBurnUpdated {
+    pub fee_burn_bps: i128,
+    pub updated_by: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DevBuyFeeWaiverUpdated {
+    pub threshold: i128,
+    pub updated_by: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRecipientsUpdated {
+    pub recipients: Vec<(Address, u32)>,
+    pub updated_by: Address,
+}
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FeeConfigUpdated {
@@ -208,6 +355,14 @@ pub struct FeeConfigUpdated {
     pub updated_by: Address,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TradingFeesUpdated {
+    pub buy_fee_bps: i128,
+    pub sell_fee_bps: i128,
+    pub updated_by: Address,
+}
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TreasuryUpdated {
@@ -216,6 +371,53 @@ pub struct TreasuryUpdated {
     pub updated_by: Address,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GraduationSplitUpdated {
+    pub graduation_creator_bps: i128,
+    pub updated_by: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GraduationFeeUpdated {
+    pub graduation_fee_bps: i128,
+    pub updated_by: Address,
+}
+
+/// Timelocked fee withdrawal queued
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalRequested {
+    pub amount: i128,
+    pub executable_at: u64,
+    pub requested_by: Address,
+}
+
+/// Pending fee withdrawal dropped before execution
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalCancelled {
+    pub amount: i128,
+    pub cancelled_by: Address,
+}
+
+/// Accrued XLM fees paid out to the treasury (or fee split)
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeesWithdrawn {
+    pub amount: i128,
+    pub withdrawn_by: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalTimelockUpdated {
+    pub delay: u64,
+    pub instant_threshold: i128,
+    pub updated_by: Address,
+}
+
 // ========== Event Publishers ==========
 
 pub fn token_launched_detailed(
@@ -240,28 +442,177 @@ pub fn token_launched_detailed(
     }.publish(env);
 }
 
-pub fn tokens_bought_detailed(
+/// Amounts and prices of one buy, for `tokens_bought_detailed`
+pub struct BuyDetails {
+    pub xlm_amount: i128,
+    pub tokens_gross: i128,
+    pub fee_amount: i128,
+    pub fee_bps: i128,
+    pub tokens_net: i128,
+    pub price_before: i128,
+    pub price_after: i128,
+    pub slippage_bps: i128,
+}
+
+pub fn tokens_bought_detailed(env: &Env, buyer: &Address, payer: &Address, token: &Address, details: &BuyDetails) {
+    TokensBoughtDetailed {
+        buyer: buyer.clone(),
+        payer: payer.clone(),
+        token: token.clone(),
+        xlm_amount: details.xlm_amount,
+        tokens_gross: details.tokens_gross,
+        fee_amount: details.fee_amount,
+        fee_bps: details.fee_bps,
+        tokens_net: details.tokens_net,
+        price_before: details.price_before,
+        price_after: details.price_after,
+        slippage_bps: details.slippage_bps,
+        timestamp: env.ledger().timestamp(),
+    }.publish(env);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn tokens_sold_detailed(
     env: &Env,
-    buyer: &Address,
+    seller: &Address,
     token: &Address,
-    xlm_amount: i128,
-    tokens_gross: i128,
+    tokens_sold: i128,
+    xlm_gross: i128,
     fee_amount: i128,
-    tokens_net: i128,
+    fee_bps: i128,
+    xlm_net: i128,
     price_before: i128,
     price_after: i128,
-    slippage_bps: i128,
 ) {
-    TokensBoughtDetailed {
-        buyer: buyer.clone(),
+    TokensSoldDetailed {
+        seller: seller.clone(),
         token: token.clone(),
-        xlm_amount,
-        tokens_gross,
+        tokens_sold,
+        xlm_gross,
         fee_amount,
-        tokens_net,
+        fee_bps,
+        xlm_net,
         price_before,
         price_after,
-        slippage_bps,
+        timestamp: env.ledger().timestamp(),
+    }.publish(env);
+}
+
+pub fn fee_direction_updated(env: &Env, fee_in_xlm: bool, updated_by: &Address) {
+    FeeDirectionUpdated {
+        fee_in_xlm,
+        updated_by: updated_by.clone(),
+    }.publish(env);
+}
+
+pub fn fee_burn_updated(env: &Env, fee_burn_bps: i128, updated_by: &Address) {
+    FeeBurnUpdated {
+        fee_burn_bps,
+        updated_by: updated_by.clone(),
+    }.publish(env);
+}
+
+pub fn dev_buy_fee_waiver_updated(env: &Env, threshold: i128, updated_by: &Address) {
+    DevBuyFeeWaiverUpdated {
+        threshold,
+        updated_by: updated_by.clone(),
+    }.publish(env);
+}
+
+pub fn fee_recipients_updated(env: &Env, recipients: &Vec<(Address, u32)>, updated_by: &Address) {
+    FeeRecipientsUpdated {
+        recipients: recipients.clone(),
+        updated_by: updated_by.clone(),
+    }.publish(env);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn graduation_detailed(
+    env: &Env,
+    token: &Address,
+    xlm_raised: i128,
+    tokens_graduated: i128,
+    amm_pair_address: &Address,
+    lp_tokens_burned: i128,
+    creator_allocation: i128,
+    graduation_fee: i128,
+    graduated_by: &Address,
+) {
+    GraduationDetailed {
+        token: token.clone(),
+        xlm_raised,
+        tokens_graduated,
+        amm_pair_address: amm_pair_address.clone(),
+        lp_tokens_burned,
+        creator_allocation,
+        graduation_fee,
+        graduated_by: graduated_by.clone(),
+        timestamp: env.ledger().timestamp(),
+    }.publish(env);
+}
+
+pub fn creator_allocation_claimed(env: &Env, creator: &Address, token: &Address, amount: i128) {
+    CreatorAllocationClaimed {
+        creator: creator.clone(),
+        token: token.clone(),
+        amount,
+    }.publish(env);
+}
+
+pub fn sells_toggled(env: &Env, token: &Address, creator: &Address, enabled: bool, resumes_at: u64) {
+    SellsToggled {
+        token: token.clone(),
+        creator: creator.clone(),
+        enabled,
+        resumes_at,
+    }.publish(env);
+}
+
+pub fn metadata_updated(
+    env: &Env,
+    token: &Address,
+    image_url: &String,
+    description: &String,
+    updated_by: &Address,
+) {
+    MetadataUpdated {
+        token: token.clone(),
+        image_url: image_url.clone(),
+        description: description.clone(),
+        updated_by: updated_by.clone(),
+    }.publish(env);
+}
+
+pub fn token_ownership_transferred(env: &Env, token: &Address, previous_creator: &Address, new_creator: &Address) {
+    TokenOwnershipTransferred {
+        token: token.clone(),
+        previous_creator: previous_creator.clone(),
+        new_creator: new_creator.clone(),
+    }.publish(env);
+}
+
+pub fn token_swept(env: &Env, token: &Address, treasury: &Address, amount: i128, swept_by: &Address) {
+    TokenSwept {
+        token: token.clone(),
+        treasury: treasury.clone(),
+        amount,
+        swept_by: swept_by.clone(),
+    }.publish(env);
+}
+
+pub fn fee_burned(env: &Env, token: &Address, amount: i128) {
+    FeeBurned {
+        token: token.clone(),
+        amount,
+    }.publish(env);
+}
+
+pub fn initialized(env: &Env, admin: &Address, treasury: &Address, creation_fee: i128, trading_fee_bps: i128) {
+    Initialized {
+        admin: admin.clone(),
+        treasury: treasury.clone(),
+        creation_fee,
+        trading_fee_bps,
         timestamp: env.ledger().timestamp(),
     }.publish(env);
 }
@@ -301,6 +652,20 @@ pub fn contract_unpaused(env: &Env, unpaused_by: &Address) {
     }.publish(env);
 }
 
+pub fn trading_pause_updated(env: &Env, paused: bool, updated_by: &Address) {
+    TradingPauseUpdated {
+        paused,
+        updated_by: updated_by.clone(),
+    }.publish(env);
+}
+
+pub fn launches_pause_updated(env: &Env, paused: bool, updated_by: &Address) {
+    LaunchesPauseUpdated {
+        paused,
+        updated_by: updated_by.clone(),
+    }.publish(env);
+}
+
 pub fn fee_config_updated(env: &Env, creation_fee: i128, trading_fee_bps: i128, updated_by: &Address) {
     FeeConfigUpdated {
         creation_fee,
@@ -309,6 +674,14 @@ pub fn fee_config_updated(env: &Env, creation_fee: i128, trading_fee_bps: i128,
     }.publish(env);
 }
 
+pub fn trading_fees_updated(env: &Env, buy_fee_bps: i128, sell_fee_bps: i128, updated_by: &Address) {
+    TradingFeesUpdated {
+        buy_fee_bps,
+        sell_fee_bps,
+        updated_by: updated_by.clone(),
+    }.publish(env);
+}
+
 pub fn treasury_updated(env: &Env, old_treasury: &Address, new_treasury: &Address, updated_by: &Address) {
     TreasuryUpdated {
         old_treasury: old_treasury.clone(),
@@ -316,3 +689,47 @@ pub fn treasury_updated(env: &Env, old_treasury: &Address, new_treasury: &Addres
         updated_by: updated_by.clone(),
     }.publish(env);
 }
+
+pub fn graduation_split_updated(env: &Env, graduation_creator_bps: i128, updated_by: &Address) {
+    GraduationSplitUpdated {
+        graduation_creator_bps,
+        updated_by: updated_by.clone(),
+    }.publish(env);
+}
+
+pub fn graduation_fee_updated(env: &Env, graduation_fee_bps: i128, updated_by: &Address) {
+    GraduationFeeUpdated {
+        graduation_fee_bps,
+        updated_by: updated_by.clone(),
+    }.publish(env);
+}
+
+pub fn withdrawal_requested(env: &Env, amount: i128, executable_at: u64, requested_by: &Address) {
+    WithdrawalRequested {
+        amount,
+        executable_at,
+        requested_by: requested_by.clone(),
+    }.publish(env);
+}
+
+pub fn withdrawal_cancelled(env: &Env, amount: i128, cancelled_by: &Address) {
+    WithdrawalCancelled {
+        amount,
+        cancelled_by: cancelled_by.clone(),
+    }.publish(env);
+}
+
+pub fn fees_withdrawn(env: &Env, amount: i128, withdrawn_by: &Address) {
+    FeesWithdrawn {
+        amount,
+        withdrawn_by: withdrawn_by.clone(),
+    }.publish(env);
+}
+
+pub fn withdrawal_timelock_updated(env: &Env, delay: u64, instant_threshold: i128, updated_by: &Address) {
+    WithdrawalTimelockUpdated {
+        delay,
+        instant_threshold,
+        updated_by: updated_by.clone(),
+    }.publish(env);
+}
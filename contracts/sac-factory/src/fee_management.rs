@@ -3,7 +3,7 @@
 //! Handles all fee-related operations including collection,
 //! configuration, and distribution.
 
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, Env, Map, Symbol, TryFromVal, Val, Vec};
 use crate::errors::Error;
 use crate::math;
 use crate::access_control::Role;
@@ -15,29 +15,74 @@ use crate::events;
 pub struct FeeConfig {
     /// Fee for creating a new token (in stroops)
     pub creation_fee: i128,
-    /// Trading fee in basis points (100 = 1%)
-    pub trading_fee_bps: i128,
+    /// Buy fee in basis points (100 = 1%)
+    pub buy_fee_bps: i128,
+    /// Sell fee in basis points (may exceed `buy_fee_bps` to discourage dumps)
+    pub sell_fee_bps: i128,
     /// Treasury address that receives fees
     pub treasury: Address,
+    /// Share of graduation token liquidity carved out for the creator (basis points)
+    pub graduation_creator_bps: i128,
+    /// Charge buy fees in XLM (sent to treasury) instead of withholding tokens
+    pub fee_in_xlm: bool,
+    /// Portion of the buy fee burned when charged in tokens (basis points of the trade, <= buy_fee_bps)
+    pub fee_burn_bps: i128,
+    /// Dev buy (in stroops) at or above which the creation fee is waived (0 = never waived)
+    pub dev_buy_fee_waiver_threshold: i128,
+    /// Share of the raised XLM sent to the treasury at graduation (basis points)
+    pub graduation_fee_bps: i128,
+}
+
+/// Maximum buy or sell fee (10%), enforced at init and on every update
+pub const MAX_TRADING_FEE_BPS: i128 = 1000;
+
+/// Maximum creator share of graduation token liquidity (10%)
+pub const MAX_GRADUATION_CREATOR_BPS: i128 = 1000;
+
+/// Maximum graduation fee (10% of the raised XLM)
+pub const MAX_GRADUATION_FEE_BPS: i128 = 1000;
+
+/// Maximum fee split recipients (bounds the transfers made per fee)
+pub const MAX_FEE_RECIPIENTS: u32 = 10;
+
+/// Side of a trade, for picking the matching fee rate
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TradeDirection {
+    Buy,
+    Sell,
 }
 
 impl FeeConfig {
+    /// Create a config charging `trading_fee_bps` on both buys and sells
     pub fn new(creation_fee: i128, trading_fee_bps: i128, treasury: Address) -> Result<Self, Error> {
         // Validate fees
         if creation_fee < 0 {
             return Err(Error::InvalidFeeConfiguration);
         }
-        if trading_fee_bps < 0 || trading_fee_bps > 10_000 {
-            // Max 100% fee
+        if !(0..=MAX_TRADING_FEE_BPS).contains(&trading_fee_bps) {
             return Err(Error::FeeTooHigh);
         }
 
         Ok(FeeConfig {
             creation_fee,
-            trading_fee_bps,
+            buy_fee_bps: trading_fee_bps,
+            sell_fee_bps: trading_fee_bps,
             treasury,
+            graduation_creator_bps: 0,
+            fee_in_xlm: false,
+            fee_burn_bps: 0,
+            dev_buy_fee_waiver_threshold: 0,
+            graduation_fee_bps: 0,
         })
     }
+
+    /// Fee rate for one side of a trade
+    pub fn fee_bps(&self, direction: TradeDirection) -> i128 {
+        match direction {
+            TradeDirection::Buy => self.buy_fee_bps,
+            TradeDirection::Sell => self.sell_fee_bps,
+        }
+    }
 }
 
 /// Storage key for fee config
@@ -45,24 +90,123 @@ impl FeeConfig {
 #[contracttype]
 pub enum FeeKey {
     Config,
+    Recipients,         // Vec<(recipient, bps)> fee split (empty = all to treasury)
+    WithdrawalTimelock, // WithdrawalTimelock settings
+    PendingWithdrawal,  // PendingWithdrawal awaiting its delay
+}
+
+/// Default delay before a requested fee withdrawal can execute (24 hours)
+pub const DEFAULT_WITHDRAWAL_DELAY: u64 = 86_400;
+
+/// Timelock settings for withdrawing accrued fees
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalTimelock {
+    /// Seconds between `request_withdrawal` and `execute_withdrawal`
+    pub delay: u64,
+    /// Withdrawals below this amount (in stroops) skip the timelock (0 = none do)
+    pub instant_threshold: i128,
+}
+
+/// A fee withdrawal waiting out the timelock
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingWithdrawal {
+    pub amount: i128,
+    pub requested_at: u64,
+    /// Earliest execution time, fixed at request so later config changes can't shorten it
+    pub executable_at: u64,
 }
 
 /// Get fee configuration
-pub fn get_fee_config(env: &Env) -> FeeConfig {
-    env.storage()
-        .persistent()
-        .get(&FeeKey::Config)
-        .unwrap_or_else(|| {
+///
+/// A config saved before the buy/sell split (single `trading_fee_bps`) is
+/// upgraded on read, charging the old rate on both sides. A record that
+/// decodes as neither layout is reported as `InvalidState`.
+pub fn get_fee_config(env: &Env) -> Result<FeeConfig, Error> {
+    let raw: Option<Val> = env.storage().persistent().get(&FeeKey::Config);
+
+    match raw {
+        Some(raw) => {
+            let fields = Map::<Symbol, Val>::try_from_val(env, &raw).map_err(|_| Error::InvalidState)?;
+            if is_legacy_fee_config(env, &fields) {
+                upgrade_legacy_fee_config(env, &fields)
+            } else {
+                read_fee_config(env, &fields)
+            }
+        }
+        None => {
             // Default config
-            FeeConfig {
+            Ok(FeeConfig {
                 creation_fee: 100_000,     // 0.01 XLM
-                trading_fee_bps: 100,       // 1%
+                buy_fee_bps: 100,           // 1%
+                sell_fee_bps: 100,          // 1%
                 treasury: env.current_contract_address(), // Fallback
-            }
-        })
+                graduation_creator_bps: 0,
+                fee_in_xlm: false,
+                fee_burn_bps: 0,
+                dev_buy_fee_waiver_threshold: 0,
+                graduation_fee_bps: 0,
+            })
+        }
+    }
+}
+
+/// Fields of the original single-rate `FeeConfig`
+const LEGACY_FEE_CONFIG_FIELDS: [&str; 3] = ["creation_fee", "trading_fee_bps", "treasury"];
+
+/// Whether a stored record has exactly the original single-rate layout
+fn is_legacy_fee_config(env: &Env, raw: &Map<Symbol, Val>) -> bool {
+    raw.len() == LEGACY_FEE_CONFIG_FIELDS.len() as u32
+        && LEGACY_FEE_CONFIG_FIELDS
+            .iter()
+            .all(|name| raw.contains_key(Symbol::new(env, name)))
+}
+
+/// Read a stored field by name, or `InvalidState` if it is missing or mistyped
+///
+/// Decoding the whole record at once traps on a missing field, so records
+/// are read one field at a time.
+fn fee_config_field<T: TryFromVal<Env, Val>>(env: &Env, raw: &Map<Symbol, Val>, name: &str) -> Result<T, Error> {
+    raw.get(Symbol::new(env, name))
+        .and_then(|value| T::try_from_val(env, &value).ok())
+        .ok_or(Error::InvalidState)
+}
+
+/// Read a record saved in the current layout
+fn read_fee_config(env: &Env, raw: &Map<Symbol, Val>) -> Result<FeeConfig, Error> {
+    Ok(FeeConfig {
+        creation_fee: fee_config_field(env, raw, "creation_fee")?,
+        buy_fee_bps: fee_config_field(env, raw, "buy_fee_bps")?,
+        sell_fee_bps: fee_config_field(env, raw, "sell_fee_bps")?,
+        treasury: fee_config_field(env, raw, "treasury")?,
+        graduation_creator_bps: fee_config_field(env, raw, "graduation_creator_bps")?,
+        fee_in_xlm: fee_config_field(env, raw, "fee_in_xlm")?,
+        fee_burn_bps: fee_config_field(env, raw, "fee_burn_bps")?,
+        dev_buy_fee_waiver_threshold: fee_config_field(env, raw, "dev_buy_fee_waiver_threshold")?,
+        graduation_fee_bps: fee_config_field(env, raw, "graduation_fee_bps")?,
+    })
 }
 
-/// Set fee configuration (only FeeAdmin)
+/// Build the current `FeeConfig` from a single-rate record, charging
+/// `trading_fee_bps` on both sides and defaulting the later fields
+fn upgrade_legacy_fee_config(env: &Env, raw: &Map<Symbol, Val>) -> Result<FeeConfig, Error> {
+    let trading_fee_bps: i128 = fee_config_field(env, raw, "trading_fee_bps")?;
+
+    Ok(FeeConfig {
+        creation_fee: fee_config_field(env, raw, "creation_fee")?,
+        buy_fee_bps: trading_fee_bps,
+        sell_fee_bps: trading_fee_bps,
+        treasury: fee_config_field(env, raw, "treasury")?,
+        graduation_creator_bps: 0,
+        fee_in_xlm: false,
+        fee_burn_bps: 0,
+        dev_buy_fee_waiver_threshold: 0,
+        graduation_fee_bps: 0,
+    })
+}
+
+/// Set fee configuration, charging `trading_fee_bps` on both buys and sells (only FeeAdmin)
 pub fn set_fee_config(
     env: &Env,
     admin: &Address,
@@ -78,18 +222,22 @@ pub fn set_fee_config(
     }
 
     // Validate new config
-    let mut config = get_fee_config(env);
+    let mut config = get_fee_config(env)?;
     config.creation_fee = creation_fee;
-    config.trading_fee_bps = trading_fee_bps;
+    config.buy_fee_bps = trading_fee_bps;
+    config.sell_fee_bps = trading_fee_bps;
 
     // Ensure valid
     if creation_fee < 0 {
         return Err(Error::InvalidFeeConfiguration);
     }
-    if trading_fee_bps < 0 || trading_fee_bps > 1000 {
-        // Max 10% trading fee
+    if !(0..=MAX_TRADING_FEE_BPS).contains(&trading_fee_bps) {
         return Err(Error::FeeTooHigh);
     }
+    // The burn share must still fit inside the trading fee
+    if trading_fee_bps < config.fee_burn_bps {
+        return Err(Error::InvalidFeeConfiguration);
+    }
 
     // Save
     env.storage().persistent().set(&FeeKey::Config, &config);
@@ -100,6 +248,39 @@ pub fn set_fee_config(
     Ok(())
 }
 
+/// Set separate buy and sell fee rates (only FeeAdmin or Owner)
+pub fn set_trading_fees(env: &Env, admin: &Address, buy_fee_bps: i128, sell_fee_bps: i128) -> Result<(), Error> {
+    admin.require_auth();
+
+    // Only FeeAdmin or Owner can update fees
+    if !crate::access_control::has_role(env, admin, Role::FeeAdmin)
+        && !crate::access_control::has_role(env, admin, Role::Owner) {
+        return Err(Error::Unauthorized);
+    }
+
+    if !(0..=MAX_TRADING_FEE_BPS).contains(&buy_fee_bps)
+        || !(0..=MAX_TRADING_FEE_BPS).contains(&sell_fee_bps) {
+        return Err(Error::FeeTooHigh);
+    }
+
+    let mut config = get_fee_config(env)?;
+
+    // The burn share is taken from buy fees, so it must still fit inside them
+    if buy_fee_bps < config.fee_burn_bps {
+        return Err(Error::InvalidFeeConfiguration);
+    }
+
+    config.buy_fee_bps = buy_fee_bps;
+    config.sell_fee_bps = sell_fee_bps;
+
+    env.storage().persistent().set(&FeeKey::Config, &config);
+
+    // Emit event
+    events::trading_fees_updated(env, buy_fee_bps, sell_fee_bps, admin);
+
+    Ok(())
+}
+
 /// Update treasury address (only TreasuryAdmin or Owner)
 pub fn set_treasury(env: &Env, admin: &Address, new_treasury: &Address) -> Result<(), Error> {
     admin.require_auth();
@@ -110,7 +291,7 @@ pub fn set_treasury(env: &Env, admin: &Address, new_treasury: &Address) -> Resul
         return Err(Error::Unauthorized);
     }
 
-    let mut config = get_fee_config(env);
+    let mut config = get_fee_config(env)?;
     let old_treasury = config.treasury.clone();
     config.treasury = new_treasury.clone();
 
@@ -122,6 +303,242 @@ pub fn set_treasury(env: &Env, admin: &Address, new_treasury: &Address) -> Resul
     Ok(())
 }
 
+/// Get the fee split (empty when every fee goes to the treasury)
+pub fn get_fee_recipients(env: &Env) -> Vec<(Address, u32)> {
+    env.storage()
+        .persistent()
+        .get(&FeeKey::Recipients)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Split fees across several recipients by bps (only TreasuryAdmin or Owner)
+///
+/// An empty list clears the split, so fees go to the treasury again.
+pub fn set_fee_recipients(env: &Env, admin: &Address, recipients: &Vec<(Address, u32)>) -> Result<(), Error> {
+    admin.require_auth();
+
+    // Check permissions
+    if !crate::access_control::has_role(env, admin, Role::TreasuryAdmin)
+        && !crate::access_control::has_role(env, admin, Role::Owner) {
+        return Err(Error::Unauthorized);
+    }
+
+    validate_fee_recipients(recipients)?;
+
+    if recipients.is_empty() {
+        env.storage().persistent().remove(&FeeKey::Recipients);
+    } else {
+        env.storage().persistent().set(&FeeKey::Recipients, recipients);
+    }
+
+    // Emit event
+    events::fee_recipients_updated(env, recipients, admin);
+
+    Ok(())
+}
+
+/// Check a fee split: empty, or up to `MAX_FEE_RECIPIENTS` positive shares summing to 10000 bps
+pub fn validate_fee_recipients(recipients: &Vec<(Address, u32)>) -> Result<(), Error> {
+    if recipients.is_empty() {
+        return Ok(());
+    }
+    if recipients.len() > MAX_FEE_RECIPIENTS {
+        return Err(Error::InvalidFeeConfiguration);
+    }
+
+    let mut total_bps: u32 = 0;
+    for (_, bps) in recipients.iter() {
+        if !(1..=10_000).contains(&bps) {
+            return Err(Error::InvalidFeeConfiguration);
+        }
+        total_bps += bps;
+    }
+
+    if total_bps != 10_000 {
+        return Err(Error::InvalidFeeConfiguration);
+    }
+
+    Ok(())
+}
+
+/// Divide a fee among the split recipients, or give it all to `treasury` if there is no split
+///
+/// The last recipient takes the rounding remainder, so the shares always sum to `amount`.
+pub fn split_fee(
+    env: &Env,
+    amount: i128,
+    treasury: &Address,
+    recipients: &Vec<(Address, u32)>,
+) -> Result<Vec<(Address, i128)>, Error> {
+    let mut shares = Vec::new(env);
+
+    if recipients.is_empty() {
+        shares.push_back((treasury.clone(), amount));
+        return Ok(shares);
+    }
+
+    let mut remaining = amount;
+    let last = recipients.len() - 1;
+    for (i, (recipient, bps)) in recipients.iter().enumerate() {
+        let share = if i as u32 == last {
+            remaining
+        } else {
+            math::apply_bps(amount, bps as i128)?
+        };
+        remaining = math::safe_sub(remaining, share)?;
+        shares.push_back((recipient, share));
+    }
+
+    Ok(shares)
+}
+
+/// Pay a fee in `asset` from `from` to the fee split (or the treasury)
+fn pay_fee(env: &Env, asset: &Address, from: &Address, amount: i128) -> Result<(), Error> {
+    let config = get_fee_config(env)?;
+    let shares = split_fee(env, amount, &config.treasury, &get_fee_recipients(env))?;
+
+    let asset_client = soroban_sdk::token::Client::new(env, asset);
+    for (recipient, share) in shares.iter() {
+        if share > 0 {
+            asset_client.transfer(from, &recipient, &share);
+        }
+    }
+
+    Ok(())
+}
+
+/// Update creator share of graduation liquidity (only FeeAdmin or Owner)
+pub fn set_graduation_creator_bps(
+    env: &Env,
+    admin: &Address,
+    graduation_creator_bps: i128,
+) -> Result<(), Error> {
+    admin.require_auth();
+
+    // Only FeeAdmin or Owner can update fees
+    if !crate::access_control::has_role(env, admin, Role::FeeAdmin)
+        && !crate::access_control::has_role(env, admin, Role::Owner) {
+        return Err(Error::Unauthorized);
+    }
+
+    // Keep enough token liquidity for a functional pool
+    if !(0..=MAX_GRADUATION_CREATOR_BPS).contains(&graduation_creator_bps) {
+        return Err(Error::FeeTooHigh);
+    }
+
+    let mut config = get_fee_config(env)?;
+    config.graduation_creator_bps = graduation_creator_bps;
+
+    env.storage().persistent().set(&FeeKey::Config, &config);
+
+    // Emit event
+    events::graduation_split_updated(env, graduation_creator_bps, admin);
+
+    Ok(())
+}
+
+/// Update the share of raised XLM taken at graduation (only FeeAdmin or Owner)
+pub fn set_graduation_fee_bps(
+    env: &Env,
+    admin: &Address,
+    graduation_fee_bps: i128,
+) -> Result<(), Error> {
+    admin.require_auth();
+
+    // Only FeeAdmin or Owner can update fees
+    if !crate::access_control::has_role(env, admin, Role::FeeAdmin)
+        && !crate::access_control::has_role(env, admin, Role::Owner) {
+        return Err(Error::Unauthorized);
+    }
+
+    if !(0..=MAX_GRADUATION_FEE_BPS).contains(&graduation_fee_bps) {
+        return Err(Error::FeeTooHigh);
+    }
+
+    let mut config = get_fee_config(env)?;
+    config.graduation_fee_bps = graduation_fee_bps;
+
+    env.storage().persistent().set(&FeeKey::Config, &config);
+
+    // Emit event
+    events::graduation_fee_updated(env, graduation_fee_bps, admin);
+
+    Ok(())
+}
+
+/// Choose whether buy fees are charged in XLM or tokens (only FeeAdmin or Owner)
+pub fn set_fee_in_xlm(env: &Env, admin: &Address, fee_in_xlm: bool) -> Result<(), Error> {
+    admin.require_auth();
+
+    // Only FeeAdmin or Owner can update fees
+    if !crate::access_control::has_role(env, admin, Role::FeeAdmin)
+        && !crate::access_control::has_role(env, admin, Role::Owner) {
+        return Err(Error::Unauthorized);
+    }
+
+    let mut config = get_fee_config(env)?;
+    config.fee_in_xlm = fee_in_xlm;
+
+    env.storage().persistent().set(&FeeKey::Config, &config);
+
+    // Emit event
+    events::fee_direction_updated(env, fee_in_xlm, admin);
+
+    Ok(())
+}
+
+/// Update the burned share of token-denominated trading fees (only FeeAdmin or Owner)
+pub fn set_fee_burn_bps(env: &Env, admin: &Address, fee_burn_bps: i128) -> Result<(), Error> {
+    admin.require_auth();
+
+    // Only FeeAdmin or Owner can update fees
+    if !crate::access_control::has_role(env, admin, Role::FeeAdmin)
+        && !crate::access_control::has_role(env, admin, Role::Owner) {
+        return Err(Error::Unauthorized);
+    }
+
+    let mut config = get_fee_config(env)?;
+
+    // Can't burn more than the (buy) fee itself
+    if !(0..=config.buy_fee_bps).contains(&fee_burn_bps) {
+        return Err(Error::FeeTooHigh);
+    }
+
+    config.fee_burn_bps = fee_burn_bps;
+
+    env.storage().persistent().set(&FeeKey::Config, &config);
+
+    // Emit event
+    events::fee_burn_updated(env, fee_burn_bps, admin);
+
+    Ok(())
+}
+
+/// Update the dev buy size that waives the creation fee (only FeeAdmin or Owner)
+pub fn set_dev_buy_fee_waiver_threshold(env: &Env, admin: &Address, threshold: i128) -> Result<(), Error> {
+    admin.require_auth();
+
+    // Only FeeAdmin or Owner can update fees
+    if !crate::access_control::has_role(env, admin, Role::FeeAdmin)
+        && !crate::access_control::has_role(env, admin, Role::Owner) {
+        return Err(Error::Unauthorized);
+    }
+
+    if threshold < 0 {
+        return Err(Error::InvalidFeeConfiguration);
+    }
+
+    let mut config = get_fee_config(env)?;
+    config.dev_buy_fee_waiver_threshold = threshold;
+
+    env.storage().persistent().set(&FeeKey::Config, &config);
+
+    // Emit event
+    events::dev_buy_fee_waiver_updated(env, threshold, admin);
+
+    Ok(())
+}
+
 /// Calculate trading fee amount
 pub fn calculate_trading_fee(amount: i128, fee_bps: i128) -> Result<i128, Error> {
     math::apply_bps(amount, fee_bps)
@@ -129,61 +546,283 @@ pub fn calculate_trading_fee(amount: i128, fee_bps: i128) -> Result<i128, Error>
 
 /// Collect creation fee from user
 ///
-/// Transfers XLM from creator to treasury using native XLM SAC
+/// Transfers XLM from creator to treasury using native XLM SAC. Nothing is
+/// charged when `initial_buy_xlm` reaches the dev buy waiver threshold.
+///
+/// Returns: fee actually paid
 pub fn collect_creation_fee(
     env: &Env,
     from: &Address,
+    initial_buy_xlm: i128,
 ) -> Result<i128, Error> {
-    let config = get_fee_config(env);
+    let config = get_fee_config(env)?;
 
     if config.creation_fee == 0 {
         return Ok(0);
     }
 
-    // In test environment, skip actual XLM transfer
-    // In production, this transfers XLM from creator to treasury using native XLM SAC
-    #[cfg(not(test))]
-    {
-        use soroban_sdk::token;
+    let threshold = config.dev_buy_fee_waiver_threshold;
+    if threshold > 0 && initial_buy_xlm >= threshold {
+        return Ok(0);
+    }
+
+    // Transfer XLM from creator to treasury (or the fee split) using native XLM SAC
+    let xlm_address = soroban_sdk::Address::from_string(&soroban_sdk::String::from_str(
+        env,
+        "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC" // Testnet XLM SAC
+    ));
+    pay_fee(env, &xlm_address, from, config.creation_fee)?;
 
-        // Get native XLM SAC address
-        let xlm_address = soroban_sdk::Address::from_string(&soroban_sdk::String::from_str(
-            env,
-            "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC" // Testnet XLM SAC
-        ));
+    Ok(config.creation_fee)
+}
 
-        // Transfer XLM from creator to treasury
-        let xlm_client = token::Client::new(env, &xlm_address);
-        xlm_client.transfer(from, &config.treasury, &config.creation_fee);
+/// Forward a quote-asset (XLM, USDC, ...) trading fee held by the contract to the treasury
+/// (or the fee split)
+pub fn send_quote_fee_to_treasury(env: &Env, quote_asset: &Address, fee_amount: i128) -> Result<(), Error> {
+    if fee_amount <= 0 {
+        return Ok(());
     }
 
-    // Suppress unused variable warning in test mode
-    #[cfg(test)]
-    let _ = from;
+    pay_fee(env, quote_asset, &env.current_contract_address(), fee_amount)
+}
 
-    // For tests, just return the fee amount
-    Ok(config.creation_fee)
+/// Split a token-denominated fee into its burned and treasury portions
+///
+/// The burn is `fee_burn_bps` of the gross trade amount, capped at the fee.
+///
+/// Returns: (burn_amount, treasury_amount)
+pub fn split_token_fee(
+    gross_amount: i128,
+    fee_amount: i128,
+    fee_burn_bps: i128,
+) -> Result<(i128, i128), Error> {
+    let burn_amount = math::apply_bps(gross_amount, fee_burn_bps)?.min(fee_amount);
+    let treasury_amount = math::safe_sub(fee_amount, burn_amount)?;
+    Ok((burn_amount, treasury_amount))
 }
 
-/// Collect trading fee and return net amount
+/// Burn part of a token fee held by the contract and forward the rest to treasury
+/// (or the fee split)
 ///
-/// Returns: (net_amount, fee_collected)
-pub fn apply_trading_fee(
+/// Returns: amount burned
+pub fn distribute_token_fee(
     env: &Env,
+    token: &Address,
     gross_amount: i128,
-) -> Result<(i128, i128), Error> {
-    let config = get_fee_config(env);
+    fee_amount: i128,
+) -> Result<i128, Error> {
+    if fee_amount <= 0 {
+        return Ok(0);
+    }
+
+    let config = get_fee_config(env)?;
+    let (burn_amount, treasury_amount) =
+        split_token_fee(gross_amount, fee_amount, config.fee_burn_bps)?;
+
+    if burn_amount > 0 {
+        soroban_sdk::token::Client::new(env, token).burn(&env.current_contract_address(), &burn_amount);
+    }
+    if treasury_amount > 0 {
+        pay_fee(env, token, &env.current_contract_address(), treasury_amount)?;
+    }
+
+    if burn_amount > 0 {
+        events::fee_burned(env, token, burn_amount);
+    }
+
+    Ok(burn_amount)
+}
 
-    if config.trading_fee_bps == 0 {
+/// Split `gross_amount` into its net and fee parts at `fee_bps`
+///
+/// The fee rounds down, so below `ceil(10_000 / fee_bps)` stroops it is 0 and the
+/// whole gross is passed through; nothing is deducted without being reported.
+///
+/// Returns: (net_amount, fee_collected)
+pub fn split_trading_fee(gross_amount: i128, fee_bps: i128) -> Result<(i128, i128), Error> {
+    if fee_bps == 0 {
         return Ok((gross_amount, 0));
     }
 
-    let fee = calculate_trading_fee(gross_amount, config.trading_fee_bps)?;
+    let fee = calculate_trading_fee(gross_amount, fee_bps)?;
     let net = math::safe_sub(gross_amount, fee)?;
 
     Ok((net, fee))
 }
 
+/// Collect the buy or sell fee and return net amount
+///
+/// Returns: (net_amount, fee_collected)
+pub fn apply_trading_fee(
+    env: &Env,
+    gross_amount: i128,
+    direction: TradeDirection,
+) -> Result<(i128, i128), Error> {
+    split_trading_fee(gross_amount, get_fee_config(env)?.fee_bps(direction))
+}
+
+/// Fee rate to report for a trade: `fee_bps`, or 0 when the fee rounded to nothing
+pub fn effective_fee_bps(fee_amount: i128, fee_bps: i128) -> i128 {
+    if fee_amount > 0 {
+        fee_bps
+    } else {
+        0
+    }
+}
+
+/// Calculate the gross amount needed so that the buy fee leaves at least `net_amount`
+///
+/// Returns: gross_amount (rounded up)
+pub fn gross_up_for_trading_fee(
+    env: &Env,
+    net_amount: i128,
+) -> Result<i128, Error> {
+    let fee_bps = get_fee_config(env)?.buy_fee_bps;
+
+    if fee_bps == 0 {
+        return Ok(net_amount);
+    }
+
+    // gross = ceil(net * 10_000 / (10_000 - fee_bps))
+    let denominator = math::safe_sub(10_000, fee_bps)?;
+    let numerator = math::safe_add(math::safe_mul(net_amount, 10_000)?, denominator - 1)?;
+    math::safe_div(numerator, denominator)
+}
+
+// ========== Fee Withdrawal ==========
+
+fn require_treasury_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+    admin.require_auth();
+
+    // Only TreasuryAdmin or Owner can move accrued fees
+    if !crate::access_control::has_role(env, admin, Role::TreasuryAdmin)
+        && !crate::access_control::has_role(env, admin, Role::Owner) {
+        return Err(Error::Unauthorized);
+    }
+
+    Ok(())
+}
+
+/// Get the withdrawal timelock settings (24h delay, no instant withdrawals by default)
+pub fn get_withdrawal_timelock(env: &Env) -> WithdrawalTimelock {
+    env.storage()
+        .persistent()
+        .get(&FeeKey::WithdrawalTimelock)
+        .unwrap_or(WithdrawalTimelock {
+            delay: DEFAULT_WITHDRAWAL_DELAY,
+            instant_threshold: 0,
+        })
+}
+
+/// Update the withdrawal timelock (only Owner)
+///
+/// Already pending withdrawals keep the execution time they were given.
+pub fn set_withdrawal_timelock(
+    env: &Env,
+    admin: &Address,
+    delay: u64,
+    instant_threshold: i128,
+) -> Result<(), Error> {
+    admin.require_auth();
+
+    // Loosening the timelock is a governance decision
+    crate::access_control::require_role(env, admin, Role::Owner)?;
+
+    if instant_threshold < 0 {
+        return Err(Error::InvalidFeeConfiguration);
+    }
+
+    env.storage().persistent().set(
+        &FeeKey::WithdrawalTimelock,
+        &WithdrawalTimelock { delay, instant_threshold },
+    );
+
+    events::withdrawal_timelock_updated(env, delay, instant_threshold, admin);
+
+    Ok(())
+}
+
+pub fn get_pending_withdrawal(env: &Env) -> Option<PendingWithdrawal> {
+    env.storage().persistent().get(&FeeKey::PendingWithdrawal)
+}
+
+/// Pay accrued XLM fees out of the factory
+fn withdraw_accrued_fees(env: &Env, xlm_address: &Address, admin: &Address, amount: i128) -> Result<(), Error> {
+    if amount > crate::storage::get_accrued_xlm_fees(env) {
+        return Err(Error::InsufficientBalance);
+    }
+
+    crate::storage::adjust_accrued_xlm_fees(env, -amount)?;
+    send_quote_fee_to_treasury(env, xlm_address, amount)?;
+
+    events::fees_withdrawn(env, amount, admin);
+
+    Ok(())
+}
+
+/// Start withdrawing accrued XLM fees (only TreasuryAdmin or Owner)
+///
+/// Amounts below `instant_threshold` are paid out immediately. Larger ones are
+/// queued and need `execute_withdrawal` once the delay has passed; only one
+/// withdrawal may be pending at a time.
+pub fn request_withdrawal(env: &Env, xlm_address: &Address, admin: &Address, amount: i128) -> Result<(), Error> {
+    require_treasury_admin(env, admin)?;
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if amount > crate::storage::get_accrued_xlm_fees(env) {
+        return Err(Error::InsufficientBalance);
+    }
+
+    let timelock = get_withdrawal_timelock(env);
+    if amount < timelock.instant_threshold {
+        return withdraw_accrued_fees(env, xlm_address, admin, amount);
+    }
+
+    if get_pending_withdrawal(env).is_some() {
+        return Err(Error::InvalidState);
+    }
+
+    let requested_at = env.ledger().timestamp();
+    let executable_at = requested_at.saturating_add(timelock.delay);
+    env.storage().persistent().set(
+        &FeeKey::PendingWithdrawal,
+        &PendingWithdrawal { amount, requested_at, executable_at },
+    );
+
+    events::withdrawal_requested(env, amount, executable_at, admin);
+
+    Ok(())
+}
+
+/// Pay out the pending withdrawal once its delay has passed (only TreasuryAdmin or Owner)
+pub fn execute_withdrawal(env: &Env, xlm_address: &Address, admin: &Address) -> Result<i128, Error> {
+    require_treasury_admin(env, admin)?;
+
+    let pending = get_pending_withdrawal(env).ok_or(Error::NoPendingWithdrawal)?;
+    if env.ledger().timestamp() < pending.executable_at {
+        return Err(Error::WithdrawalTimelocked);
+    }
+
+    env.storage().persistent().remove(&FeeKey::PendingWithdrawal);
+    withdraw_accrued_fees(env, xlm_address, admin, pending.amount)?;
+
+    Ok(pending.amount)
+}
+
+/// Drop the pending withdrawal (only TreasuryAdmin or Owner)
+pub fn cancel_withdrawal(env: &Env, admin: &Address) -> Result<(), Error> {
+    require_treasury_admin(env, admin)?;
+
+    let pending = get_pending_withdrawal(env).ok_or(Error::NoPendingWithdrawal)?;
+    env.storage().persistent().remove(&FeeKey::PendingWithdrawal);
+
+    events::withdrawal_cancelled(env, pending.amount, admin);
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -199,8 +838,122 @@ mod tests {
         let config = FeeConfig::new(100_000, 100, treasury.clone()).unwrap();
 
         assert_eq!(config.creation_fee, 100_000);
-        assert_eq!(config.trading_fee_bps, 100);
+        assert_eq!(config.buy_fee_bps, 100);
+        assert_eq!(config.sell_fee_bps, 100);
         assert_eq!(config.treasury, treasury);
+        assert_eq!(config.graduation_creator_bps, 0);
+        assert!(!config.fee_in_xlm);
+        assert_eq!(config.fee_burn_bps, 0);
+    }
+
+    #[test]
+    fn test_fee_bps_by_direction() {
+        let env = Env::default();
+        let mut config = FeeConfig::new(100_000, 100, Address::generate(&env)).unwrap();
+        assert_eq!(config.fee_bps(TradeDirection::Buy), 100);
+        assert_eq!(config.fee_bps(TradeDirection::Sell), 100);
+
+        config.sell_fee_bps = 300;
+        assert_eq!(config.fee_bps(TradeDirection::Buy), 100);
+        assert_eq!(config.fee_bps(TradeDirection::Sell), 300);
+    }
+
+    #[test]
+    fn test_fee_config_trading_fee_cap() {
+        let env = Env::default();
+        let treasury = Address::generate(&env);
+
+        assert!(FeeConfig::new(100_000, MAX_TRADING_FEE_BPS, treasury.clone()).is_ok());
+        assert_eq!(
+            FeeConfig::new(100_000, MAX_TRADING_FEE_BPS + 1, treasury.clone()),
+            Err(Error::FeeTooHigh)
+        );
+        assert_eq!(FeeConfig::new(100_000, -1, treasury), Err(Error::FeeTooHigh));
+    }
+
+    #[test]
+    fn test_split_token_fee() {
+        // 1% fee on 10_000 with 0.25% burned: 25 burned, 75 to treasury
+        assert_eq!(split_token_fee(10_000, 100, 25).unwrap(), (25, 75));
+        // No burn configured: everything to treasury
+        assert_eq!(split_token_fee(10_000, 100, 0).unwrap(), (0, 100));
+        // Burn equal to the fee: nothing left for treasury
+        assert_eq!(split_token_fee(10_000, 100, 100).unwrap(), (100, 0));
+        // Rounding never burns more than the fee
+        assert_eq!(split_token_fee(10_000, 99, 100).unwrap(), (99, 0));
+    }
+
+    #[test]
+    fn test_split_fee_two_way() {
+        let env = Env::default();
+        let treasury = Address::generate(&env);
+        let team = Address::generate(&env);
+        let partner = Address::generate(&env);
+
+        let mut recipients = Vec::new(&env);
+        recipients.push_back((team.clone(), 7_000));
+        recipients.push_back((partner.clone(), 3_000));
+
+        let shares = split_fee(&env, 1_000, &treasury, &recipients).unwrap();
+        assert_eq!(shares.len(), 2);
+        assert_eq!(shares.get(0).unwrap(), (team, 700));
+        assert_eq!(shares.get(1).unwrap(), (partner, 300));
+    }
+
+    #[test]
+    fn test_split_fee_three_way_remainder_to_last() {
+        let env = Env::default();
+        let treasury = Address::generate(&env);
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        let c = Address::generate(&env);
+
+        let mut recipients = Vec::new(&env);
+        recipients.push_back((a.clone(), 3_333));
+        recipients.push_back((b.clone(), 3_333));
+        recipients.push_back((c.clone(), 3_334));
+
+        // 3333 bps of 100 rounds down to 33; the last share absorbs the dust
+        let shares = split_fee(&env, 100, &treasury, &recipients).unwrap();
+        assert_eq!(shares.get(0).unwrap(), (a, 33));
+        assert_eq!(shares.get(1).unwrap(), (b, 33));
+        assert_eq!(shares.get(2).unwrap(), (c, 34));
+    }
+
+    #[test]
+    fn test_split_fee_without_split_goes_to_treasury() {
+        let env = Env::default();
+        let treasury = Address::generate(&env);
+
+        let shares = split_fee(&env, 1_000, &treasury, &Vec::new(&env)).unwrap();
+        assert_eq!(shares.len(), 1);
+        assert_eq!(shares.get(0).unwrap(), (treasury, 1_000));
+    }
+
+    #[test]
+    fn test_validate_fee_recipients() {
+        let env = Env::default();
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+
+        let split = |bps_a: u32, bps_b: u32| {
+            let mut recipients = Vec::new(&env);
+            recipients.push_back((a.clone(), bps_a));
+            recipients.push_back((b.clone(), bps_b));
+            validate_fee_recipients(&recipients)
+        };
+
+        assert!(split(5_000, 5_000).is_ok());
+        assert_eq!(split(5_000, 4_999), Err(Error::InvalidFeeConfiguration));
+        assert_eq!(split(5_000, 5_001), Err(Error::InvalidFeeConfiguration));
+        assert_eq!(split(10_000, 0), Err(Error::InvalidFeeConfiguration));
+        assert!(validate_fee_recipients(&Vec::new(&env)).is_ok());
+
+        let mut too_many = Vec::new(&env);
+        for _ in 0..=MAX_FEE_RECIPIENTS {
+            too_many.push_back((a.clone(), 1));
+        }
+        assert_eq!(validate_fee_recipients(&too_many), Err(Error::InvalidFeeConfiguration));
     }
 
     #[test]
@@ -213,6 +966,21 @@ mod tests {
         assert_eq!(calculate_trading_fee(10_000, 10).unwrap(), 10);
     }
 
+    #[test]
+    fn test_split_trading_fee_rounding_boundary() {
+        // 1%: 100 stroops is the smallest amount with a 1-stroop fee
+        assert_eq!(split_trading_fee(99, 100).unwrap(), (99, 0));
+        assert_eq!(split_trading_fee(100, 100).unwrap(), (99, 1));
+        // 0.3%: ceil(10_000 / 30) = 334
+        assert_eq!(split_trading_fee(333, 30).unwrap(), (333, 0));
+        assert_eq!(split_trading_fee(334, 30).unwrap(), (333, 1));
+        // Zero rate never takes anything
+        assert_eq!(split_trading_fee(1_000_000, 0).unwrap(), (1_000_000, 0));
+
+        assert_eq!(effective_fee_bps(0, 100), 0);
+        assert_eq!(effective_fee_bps(1, 100), 100);
+    }
+
     // Tests for fee management functions with storage/auth are in src/tests.rs
     // Per Soroban best practices, functions requiring contract context should only
     // be tested through the contract client interface, not directly.
@@ -17,7 +17,8 @@
 //! - 🌟 Stellar exclusive: Multi-currency support
 
 use soroban_sdk::{
-    contract, contractimpl, token, Address, Env, String, Vec, Bytes,
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contractimpl, symbol_short, token, vec, Address, Bytes, Env, IntoVal, String, Vec,
 };
 
 mod bonding_curve;
@@ -47,21 +48,55 @@ mod oracle_tests;
 
 use bonding_curve::BondingCurve;
 use errors::Error;
-use storage::{TokenInfo, TokenStatus};
+use storage::{BuySimulation, FactoryConfig, FeeBreakdown, HealthReport, LaunchOptions, LaunchParams, TokenInfo, TokenStatus};
 
 /// Graduation threshold: $69k equivalent in XLM (at $0.12/XLM = 575,000 XLM)
 /// Adjusted to 10,000 XLM for easier testing
 const GRADUATION_THRESHOLD: i128 = 100_000_000_000; // 10,000 XLM in stroops
 
+/// Contract version, bumped on every deploy that changes behavior or signatures
+const CONTRACT_VERSION: u32 = 1;
+
 /// Creation fee in stroops (0.01 XLM)
 const CREATION_FEE: i128 = 100_000; // 0.01 XLM
 
+/// Minimum liquidity (per side) required to seed a functional AMM pool at graduation
+const MIN_GRADUATION_LIQUIDITY: i128 = 10_000_000; // 1 unit with 7 decimals
+
+/// Maximum image URL length (matches token-factory's metadata URI limit)
+const MAX_IMAGE_URL_LENGTH: u32 = 256;
+
+/// Maximum token description length
+const MAX_DESCRIPTION_LENGTH: u32 = 512;
+
+/// Maximum time a creator can keep sells paused before they resume automatically
+const MAX_SELLS_DISABLED_DURATION: u64 = 86_400; // 24 hours
+
 /// Initial token supply (1 billion tokens)
 const INITIAL_SUPPLY: i128 = 1_000_000_000_0000000; // 1B with 7 decimals
 
-/// Tokens allocated to bonding curve (80% = 800M)
+/// Tokens allocated to bonding curve by default (80% = 800M)
 const BONDING_CURVE_SUPPLY: i128 = 800_000_000_0000000;
 
+/// Smallest curve share a launch may choose (50%)
+const MIN_CURVE_SUPPLY_BPS: i128 = 5_000;
+
+/// How a graduating curve's reserves are split (see `graduation_amounts`)
+struct GraduationAmounts {
+    /// Raised XLM sent to the treasury as the graduation fee
+    graduation_fee: i128,
+    /// XLM seeded into the pool
+    xlm_liquidity: i128,
+    /// Tokens seeded into the pool
+    token_liquidity: i128,
+    /// Tokens set aside for the creator to claim
+    creator_allocation: i128,
+    /// Tokens burned to keep the opening price
+    excess_tokens: i128,
+    /// XLM sent to the treasury to keep the opening price
+    excess_xlm: i128,
+}
+
 #[contract]
 pub struct SacFactory;
 
@@ -87,19 +122,24 @@ impl SacFactory {
         storage::set_admin(&env, &admin);
         storage::set_treasury(&env, &treasury);
         storage::set_token_count(&env, 0);
+        storage::set_migration_version(&env, storage::TOKEN_INFO_VERSION);
+        storage::set_deployed_version(&env, CONTRACT_VERSION);
 
         // Initialize new modules
         access_control::initialize_access_control(&env, &admin);
         state_management::initialize_state(&env);
 
         // Initialize fee config
+        let trading_fee_bps = 100; // 1% trading fee
         let fee_config = fee_management::FeeConfig::new(
             CREATION_FEE,
-            100, // 1% trading fee
-            treasury,
+            trading_fee_bps,
+            treasury.clone(),
         )?;
         env.storage().persistent().set(&fee_management::FeeKey::Config, &fee_config);
 
+        events::initialized(&env, &admin, &treasury, CREATION_FEE, trading_fee_bps);
+
         Ok(())
     }
 
@@ -130,22 +170,57 @@ impl SacFactory {
         image_url: String,
         description: String,
         serialized_asset: Bytes,
+    ) -> Result<Address, Error> {
+        Self::launch_token_with_options(
+            env,
+            creator,
+            name,
+            symbol,
+            image_url,
+            description,
+            serialized_asset,
+            LaunchOptions::default(),
+        )
+    }
+
+    /// Launch a new meme token with optional per-token settings
+    ///
+    /// Same as `launch_token`, plus:
+    /// * `options` - Optional launch settings (see `LaunchOptions`)
+    ///
+    /// A non-XLM `quote_asset` (e.g. USDC) settles every buy, sell and fee in that
+    /// asset and becomes the other side of the AMM pair at graduation. Reserve and
+    /// threshold amounts keep their values, just in quote asset units.
+    #[allow(clippy::too_many_arguments)]
+    pub fn launch_token_with_options(
+        env: Env,
+        creator: Address,
+        name: String,
+        symbol: String,
+        image_url: String,
+        description: String,
+        serialized_asset: Bytes,
+        options: LaunchOptions,
     ) -> Result<Address, Error> {
         creator.require_auth();
 
-        // Check contract is active
-        state_management::require_active(&env)?;
+        Self::validate_launch(&env, &creator, &name, &symbol, &image_url, &description)?;
 
-        // Validate inputs
-        if name.len() == 0 || name.len() > 32 {
-            return Err(Error::InvalidName);
-        }
-        if symbol.len() == 0 || symbol.len() > 12 {
-            return Err(Error::InvalidSymbol);
+        // Validate options
+        if options.initial_buy_xlm < 0 || options.daily_buy_limit < 0 {
+            return Err(Error::InvalidAmount);
         }
+        let curve_supply = match options.curve_supply_bps {
+            0 => BONDING_CURVE_SUPPLY,
+            bps if (MIN_CURVE_SUPPLY_BPS..=10_000).contains(&bps) => math::apply_bps(INITIAL_SUPPLY, bps)?,
+            _ => return Err(Error::InvalidAmount),
+        };
+        let quote_asset = Self::resolve_quote_asset(&env, &options.quote_asset)?;
+
+        Self::require_new_asset(&env, &serialized_asset)?;
 
-        // Collect creation fee
-        let fee_paid = fee_management::collect_creation_fee(&env, &creator)?;
+        // Collect creation fee (waived for a large enough dev buy)
+        let fee_paid = fee_management::collect_creation_fee(&env, &creator, options.initial_buy_xlm)?;
 
         // Get token count for tracking
         let token_count = storage::get_token_count(&env);
@@ -154,7 +229,8 @@ impl SacFactory {
         let token_address = Self::deploy_sac_token(&env, serialized_asset)?;
 
         // Initialize bonding curve (constant product: x * y = k)
-        let bonding_curve = BondingCurve::new(BONDING_CURVE_SUPPLY)?;
+        // The rest of the supply is held back for graduation
+        let bonding_curve = BondingCurve::new_with_decay(curve_supply, options.virtual_decay_bps)?;
 
         // Create token info
         let token_info = TokenInfo {
@@ -163,19 +239,33 @@ impl SacFactory {
             token_address: token_address.clone(),
             name: name.clone(),
             symbol: symbol.clone(),
-            image_url,
-            description,
+            image_url: image_url.clone(),
+            description: description.clone(),
             created_at: env.ledger().timestamp(),
             status: TokenStatus::Bonding,
             bonding_curve,
             xlm_raised: 0,
             market_cap: 0,
             holders_count: 0,
+            trading_start_delay: options.trading_start_delay,
+            sells_enabled: true,
+            sells_disabled_at: 0,
+            price_history_enabled: options.price_history,
+            tokens_burned: 0,
+            quote_asset,
+            total_volume_xlm: 0,
+            trade_count: 0,
+            organic_volume_xlm: 0,
+            graduation_enabled: !options.disable_graduation,
+            daily_buy_limit: options.daily_buy_limit,
+            reserved_supply: math::safe_sub(INITIAL_SUPPLY, curve_supply)?,
+            fees_collected: 0,
         };
 
         // Store token info
         storage::set_token_info(&env, &token_address, &token_info);
         storage::add_creator_token(&env, &creator, &token_address);
+        storage::set_token_by_id(&env, token_count, &token_address);
         storage::increment_token_count(&env);
 
         // Emit events (both basic and detailed)
@@ -187,13 +277,197 @@ impl SacFactory {
             &name,
             &symbol,
             INITIAL_SUPPLY,
-            BONDING_CURVE_SUPPLY,
+            curve_supply,
             fee_paid,
         );
+        events::metadata_updated(&env, &token_address, &image_url, &description, &creator);
+
+        // Dev buy: creator buys on the fresh curve in the same call
+        // (atomic with launch, so exempt from the anti-snipe delay)
+        if options.initial_buy_xlm > 0 {
+            Self::process_buy(
+                &env,
+                &creator,
+                &creator,
+                &token_address,
+                options.initial_buy_xlm,
+                0,
+                env.ledger().timestamp(),
+                true,
+            )?;
+        }
 
         Ok(token_address)
     }
 
+    /// Launch a new meme token from a client-chosen salt (retry-safe)
+    ///
+    /// The contract builds the serialized asset itself, deriving the issuer from
+    /// `creator` and `salt`, so retrying with the same salt targets the same token
+    /// address (see `get_salted_launch_address`). A retry after a launch that
+    /// already landed fails cleanly with `TokenAlreadyExists` instead of deploying
+    /// a second SAC.
+    ///
+    /// # Arguments
+    /// Same as `launch_token_with_options`, with `salt` in place of `serialized_asset`
+    #[allow(clippy::too_many_arguments)]
+    pub fn launch_token_with_salt(
+        env: Env,
+        creator: Address,
+        name: String,
+        symbol: String,
+        image_url: String,
+        description: String,
+        salt: soroban_sdk::BytesN<32>,
+        options: LaunchOptions,
+    ) -> Result<Address, Error> {
+        // Idempotency: a previous attempt with this salt already launched the token,
+        // which the shared launch path rejects with `TokenAlreadyExists`
+        let serialized_asset = sac_deployment::serialize_asset_from_salt(&env, &symbol, &creator, &salt)?;
+
+        Self::launch_token_with_options(
+            env,
+            creator,
+            name,
+            symbol,
+            image_url,
+            description,
+            serialized_asset,
+            options,
+        )
+    }
+
+    /// Launch a token straight to an AMM pair, skipping the bonding curve
+    ///
+    /// Deploys the SAC and an XLM pair, seeds the pair with `xlm_liquidity` XLM
+    /// from the creator and `token_liquidity` of the new supply, and locks the LP
+    /// in the factory for good. The token is recorded as `Graduated` from the
+    /// start; the supply not put in the pool is claimable by the creator via
+    /// `claim_creator_allocation`. Charges the creation fee and follows the same
+    /// launch checks as `launch_token`.
+    ///
+    /// # Arguments
+    /// * `params` - Token metadata, serialized asset and pool seed amounts
+    /// * `deadline` - Transaction deadline timestamp
+    ///
+    /// # Returns
+    /// Tuple of (token address, AMM pair address)
+    pub fn launch_to_amm(
+        env: Env,
+        creator: Address,
+        params: LaunchParams,
+        deadline: u64,
+    ) -> Result<(Address, Address), Error> {
+        creator.require_auth();
+
+        let LaunchParams {
+            name,
+            symbol,
+            image_url,
+            description,
+            serialized_asset,
+            xlm_liquidity,
+            token_liquidity,
+        } = params;
+
+        Self::require_valid_deadline(&env, deadline)?;
+        Self::validate_launch(&env, &creator, &name, &symbol, &image_url, &description)?;
+
+        if xlm_liquidity < MIN_GRADUATION_LIQUIDITY || token_liquidity < MIN_GRADUATION_LIQUIDITY {
+            return Err(Error::InsufficientLiquidityForGraduation);
+        }
+        if token_liquidity > INITIAL_SUPPLY {
+            return Err(Error::InvalidAmount);
+        }
+
+        Self::require_new_asset(&env, &serialized_asset)?;
+
+        let fee_paid = fee_management::collect_creation_fee(&env, &creator, 0)?;
+
+        let token_address = Self::deploy_sac_token(&env, serialized_asset)?;
+        let amm_address = amm_deployment::deploy_amm_pair(
+            &env,
+            &Self::get_xlm_token_address(&env),
+            &token_address,
+            &env.current_contract_address(),
+            &Self::amm_fee_to(&env)?,
+        )?;
+
+        let token_info = TokenInfo {
+            id: storage::get_token_count(&env),
+            creator: creator.clone(),
+            token_address: token_address.clone(),
+            name: name.clone(),
+            symbol: symbol.clone(),
+            image_url: image_url.clone(),
+            description: description.clone(),
+            created_at: env.ledger().timestamp(),
+            status: TokenStatus::Graduated,
+            // No curve: price and reserve views point to the pair instead
+            bonding_curve: BondingCurve::empty(),
+            xlm_raised: 0,
+            market_cap: 0,
+            holders_count: 0,
+            trading_start_delay: 0,
+            sells_enabled: true,
+            sells_disabled_at: 0,
+            price_history_enabled: false,
+            tokens_burned: 0,
+            quote_asset: Self::get_xlm_token_address(&env),
+            total_volume_xlm: 0,
+            trade_count: 0,
+            organic_volume_xlm: 0,
+            graduation_enabled: false,
+            daily_buy_limit: 0,
+            reserved_supply: 0,
+            fees_collected: 0,
+        };
+        Self::open_direct_pair(&env, &token_info, &amm_address, xlm_liquidity, token_liquidity)?;
+
+        events::token_launched_detailed(
+            &env,
+            &creator,
+            &token_address,
+            &name,
+            &symbol,
+            INITIAL_SUPPLY,
+            0,
+            fee_paid,
+        );
+        events::metadata_updated(&env, &token_address, &image_url, &description, &creator);
+
+        Ok((token_address, amm_address))
+    }
+
+    /// Get the canonical salt for `creator`'s next launch in this ledger
+    ///
+    /// Derived from the current token count, the ledger timestamp and `creator`
+    /// (see `sac_deployment::compute_launch_salt`). Pass it to
+    /// `launch_token_with_salt`, or to `get_salted_launch_address` to preview the
+    /// token address, instead of reimplementing the derivation client-side.
+    pub fn compute_launch_salt(env: Env, creator: Address) -> soroban_sdk::BytesN<32> {
+        sac_deployment::compute_launch_salt(
+            &env,
+            &creator,
+            storage::get_token_count(&env),
+            env.ledger().timestamp(),
+        )
+    }
+
+    /// Get the token address `launch_token_with_salt` deploys for these inputs
+    ///
+    /// # Returns
+    /// The deterministic SAC address, or `InvalidSymbol` if the symbol can't be an asset code
+    pub fn get_salted_launch_address(
+        env: Env,
+        creator: Address,
+        symbol: String,
+        salt: soroban_sdk::BytesN<32>,
+    ) -> Result<Address, Error> {
+        let serialized_asset = sac_deployment::serialize_asset_from_salt(&env, &symbol, &creator, &salt)?;
+        Ok(sac_deployment::get_sac_address(&env, serialized_asset))
+    }
+
     /// Buy tokens from bonding curve
     ///
     /// # Arguments
@@ -210,6 +484,11 @@ impl SacFactory {
     /// - ✅ Real XLM transfer from buyer to contract
     /// - ✅ Real token transfer from contract to buyer
     /// - ✅ Deadline check for MEV protection
+    ///
+    /// # Atomicity
+    /// If this buy crosses the graduation threshold and `graduate_to_amm` fails,
+    /// the error aborts the whole invocation. Soroban rolls back every storage write
+    /// and token transfer made so far, so the buyer's XLM is never trapped.
     pub fn buy(
         env: Env,
         buyer: Address,
@@ -220,108 +499,137 @@ impl SacFactory {
     ) -> Result<i128, Error> {
         buyer.require_auth();
 
+        Self::process_buy(&env, &buyer, &buyer, &token, xlm_amount, min_tokens, deadline, false)
+    }
+
+    /// Buy tokens from bonding curve on behalf of another address
+    ///
+    /// `payer` signs and funds the purchase; the tokens are delivered to
+    /// `recipient`, who is treated as the buyer for the daily buy limit,
+    /// trade stats and events (`TokensBoughtDetailed` records both).
+    ///
+    /// # Arguments
+    /// * `payer` - Address funding the buy (must authorize)
+    /// * `recipient` - Address receiving the tokens
+    /// * `token` - Token address to buy
+    /// * `xlm_amount` - Amount of XLM to spend (in stroops)
+    /// * `min_tokens` - Minimum tokens to receive (slippage protection)
+    /// * `deadline` - Transaction deadline timestamp (MEV protection)
+    ///
+    /// # Returns
+    /// Amount of tokens delivered to `recipient`
+    pub fn buy_for(
+        env: Env,
+        payer: Address,
+        recipient: Address,
+        token: Address,
+        xlm_amount: i128,
+        min_tokens: i128,
+        deadline: u64,
+    ) -> Result<i128, Error> {
+        payer.require_auth();
+
+        Self::process_buy(&env, &payer, &recipient, &token, xlm_amount, min_tokens, deadline, false)
+    }
+
+    /// Buy an exact amount of tokens from bonding curve
+    ///
+    /// # Arguments
+    /// * `buyer` - Your address
+    /// * `token` - Token address to buy
+    /// * `tokens_out_desired` - Exact amount of tokens to receive (after fees)
+    /// * `max_xlm_in` - Maximum XLM to spend (slippage protection)
+    /// * `deadline` - Transaction deadline timestamp (MEV protection)
+    ///
+    /// # Returns
+    /// Amount of tokens purchased (at least `tokens_out_desired`, within rounding)
+    pub fn buy_exact_tokens(
+        env: Env,
+        buyer: Address,
+        token: Address,
+        tokens_out_desired: i128,
+        max_xlm_in: i128,
+        deadline: u64,
+    ) -> Result<i128, Error> {
+        buyer.require_auth();
+
         // 1. INPUT VALIDATION: Verify amounts are positive
-        if xlm_amount <= 0 {
-            return Err(Error::InvalidAmount);
-        }
-        if min_tokens < 0 {
+        if tokens_out_desired <= 0 || max_xlm_in <= 0 {
             return Err(Error::InvalidAmount);
         }
 
-        // 2. MEV PROTECTION: Verify deadline
-        if env.ledger().timestamp() > deadline {
-            return Err(Error::TransactionExpired);
-        }
-
-        // 3. Check contract is active
-        state_management::require_active(&env)?;
-
-        // 4. Get token info
-        let mut token_info = storage::get_token_info(&env, &token)
+        // 2. Get token info
+        let token_info = storage::get_token_info(&env, &token)
             .ok_or(Error::TokenNotFound)?;
 
-        // 5. Check if still in bonding curve phase
         if token_info.status != TokenStatus::Bonding {
             return Err(Error::AlreadyGraduated);
         }
 
-        // 6. CRITICAL FIX: Transfer XLM from buyer to contract FIRST
-        // Note: In production, this performs a real XLM transfer via the native XLM SAC
-        // TODO: In tests, we need to mock the XLM token properly
-        // For now, we skip XLM transfers in test mode to allow tests to pass
-        #[cfg(not(test))]
-        {
-            let xlm_token_address = Self::get_xlm_token_address(&env);
-            let xlm_client = token::Client::new(&env, &xlm_token_address);
-            let contract_address = env.current_contract_address();
+        // 3. Invert the curve, grossing up for the trading fee on whichever side it is charged
+        let xlm_required = if fee_management::get_fee_config(&env)?.fee_in_xlm {
+            let xlm_to_curve = token_info.bonding_curve.calculate_buy_exact_tokens(tokens_out_desired)?;
+            fee_management::gross_up_for_trading_fee(&env, xlm_to_curve)?
+        } else {
+            let tokens_gross = fee_management::gross_up_for_trading_fee(&env, tokens_out_desired)?;
+            token_info.bonding_curve.calculate_buy_exact_tokens(tokens_gross)?
+        };
 
-            // Transfer XLM from buyer to contract
-            xlm_client.transfer(&buyer, &contract_address, &xlm_amount);
+        // 4. Overpay protection
+        if xlm_required > max_xlm_in {
+            return Err(Error::SlippageExceeded);
         }
 
-        // 6. Get price before trade (for slippage calculation)
-        let price_before = token_info.bonding_curve.get_current_price();
-
-        // 7. Calculate tokens to receive from bonding curve
-        let tokens_gross = token_info.bonding_curve.calculate_buy(xlm_amount)?;
+        Self::process_buy(&env, &buyer, &buyer, &token, xlm_required, tokens_out_desired, deadline, false)
+    }
 
-        // 8. Apply trading fee
-        let (tokens_net, fee_amount) = fee_management::apply_trading_fee(&env, tokens_gross)?;
+    /// Buy tokens with slippage tolerance given in basis points
+    ///
+    /// Derives `min_tokens` as `expected * (10000 - max_slippage_bps) / 10000`
+    /// from the current curve quote, then buys exactly like `buy`.
+    ///
+    /// # Arguments
+    /// * `buyer` - Your address
+    /// * `token` - Token address to buy
+    /// * `xlm_amount` - Amount of XLM to spend (in stroops)
+    /// * `max_slippage_bps` - Tolerated shortfall from the quote (100 = 1%)
+    /// * `deadline` - Transaction deadline timestamp (MEV protection)
+    ///
+    /// # Returns
+    /// Amount of tokens purchased
+    ///
+    /// # Note
+    /// The quote is taken when the transaction executes, so it does not guard
+    /// against price moves between signing and inclusion. Use `buy` with a
+    /// `min_tokens` from `simulate_buy` for that.
+    pub fn buy_with_slippage_bps(
+        env: Env,
+        buyer: Address,
+        token: Address,
+        xlm_amount: i128,
+        max_slippage_bps: i128,
+        deadline: u64,
+    ) -> Result<i128, Error> {
+        buyer.require_auth();
 
-        // 9. Check slippage
-        if tokens_net < min_tokens {
-            return Err(Error::SlippageExceeded);
+        if !(0..=10_000).contains(&max_slippage_bps) {
+            return Err(Error::InvalidAmount);
         }
-
-        // 10. CRITICAL FIX: Transfer tokens from contract to buyer
-        // TODO: In tests, we need to mint tokens to the contract first
-        // For now, we skip token transfers in test mode
-        #[cfg(not(test))]
-        {
-            let contract_address = env.current_contract_address();
-            let token_client = token::Client::new(&env, &token);
-            token_client.transfer(&contract_address, &buyer, &tokens_net);
+        if xlm_amount <= 0 {
+            return Err(Error::InvalidAmount);
         }
 
-        // 11. Update bonding curve state (with gross amount)
-        token_info.bonding_curve.execute_buy(xlm_amount, tokens_gross)?;
-
-        // 12. Get price after trade
-        let price_after = token_info.bonding_curve.get_current_price();
-
-        // 13. Calculate actual slippage
-        let slippage_bps = math::calculate_slippage_bps(price_before, price_after)?;
-
-        // 14. Update total XLM raised
-        token_info.xlm_raised = math::safe_add(token_info.xlm_raised, xlm_amount)?;
-
-        // 15. Update market cap (XLM raised * 2 for constant product)
-        token_info.market_cap = math::safe_mul(token_info.xlm_raised, 2)?;
+        let token_info = storage::get_token_info(&env, &token)
+            .ok_or(Error::TokenNotFound)?;
 
-        // 16. Check for auto-graduation
-        if token_info.xlm_raised >= GRADUATION_THRESHOLD {
-            Self::graduate_to_amm(&env, &mut token_info)?;
+        if token_info.status != TokenStatus::Bonding {
+            return Err(Error::AlreadyGraduated);
         }
 
-        // 17. Save state
-        storage::set_token_info(&env, &token, &token_info);
-
-        // 18. Emit events (both basic and detailed)
-        events::tokens_bought(&env, &buyer, &token, xlm_amount, tokens_net);
-        events::tokens_bought_detailed(
-            &env,
-            &buyer,
-            &token,
-            xlm_amount,
-            tokens_gross,
-            fee_amount,
-            tokens_net,
-            price_before,
-            price_after,
-            slippage_bps,
-        );
+        let (_, _, expected_tokens, _) = Self::quote_buy(&env, &token_info.bonding_curve, xlm_amount)?;
+        let min_tokens = math::apply_bps(expected_tokens, 10_000 - max_slippage_bps)?;
 
-        Ok(tokens_net)
+        Self::process_buy(&env, &buyer, &buyer, &token, xlm_amount, min_tokens, deadline, false)
     }
 
     /// Sell tokens back to bonding curve
@@ -336,6 +644,11 @@ impl SacFactory {
     /// # Returns
     /// Amount of XLM received
     ///
+    /// # Errors
+    /// `AlreadyGraduated` means the curve is closed because the token moved to its
+    /// AMM pair (possibly during someone else's buy). Sell on the pair returned by
+    /// `get_post_graduation_venue` instead.
+    ///
     /// # Critical Updates (Sprint 1, Day 1)
     /// - ✅ Real token transfer from seller to contract
     /// - ✅ Real XLM transfer from contract to seller
@@ -359,12 +672,11 @@ impl SacFactory {
         }
 
         // 2. MEV PROTECTION: Verify deadline
-        if env.ledger().timestamp() > deadline {
-            return Err(Error::TransactionExpired);
-        }
+        Self::require_valid_deadline(&env, deadline)?;
 
-        // 3. Check contract is active
+        // 3. Check contract is active and trading is open
         state_management::require_active(&env)?;
+        state_management::require_trading_active(&env)?;
 
         // 3. Get token info
         let mut token_info = storage::get_token_info(&env, &token)
@@ -375,80 +687,511 @@ impl SacFactory {
             return Err(Error::AlreadyGraduated);
         }
 
+        // ANTI-SNIPE: Trading opens after the creator's launch delay
+        Self::require_trading_started(&env, &token_info)?;
+
+        // Creator sell pause (bounded by MAX_SELLS_DISABLED_DURATION)
+        Self::require_sells_enabled(&env, &token_info)?;
+
         // 5. Calculate XLM to receive from bonding curve
         let xlm_gross = token_info.bonding_curve.calculate_sell(token_amount)?;
 
-        // 6. Apply trading fee
-        let (xlm_net, _fee_amount) = fee_management::apply_trading_fee(&env, xlm_gross)?;
+        // Dust sells cost more gas than they move
+        if xlm_gross < storage::get_min_sell_xlm(&env) {
+            return Err(Error::AmountTooSmall);
+        }
+
+        // 6. Apply the sell fee
+        let (xlm_net, fee_amount) =
+            fee_management::apply_trading_fee(&env, xlm_gross, fee_management::TradeDirection::Sell)?;
 
         // 7. Check slippage
         if xlm_net < min_xlm {
             return Err(Error::SlippageExceeded);
         }
 
-        // 8. CRITICAL FIX: Transfer tokens from seller to contract FIRST
-        // 9. CRITICAL FIX: Transfer XLM from contract to seller
-        // TODO: In tests, we need to mock both token and XLM transfers
-        // For now, we skip transfers in test mode
-        #[cfg(not(test))]
-        {
-            let token_client = token::Client::new(&env, &token);
-            let contract_address = env.current_contract_address();
-
-            token_client.transfer(&seller, &contract_address, &token_amount);
+        // 8. EFFECTS: Update bonding curve state (using gross amount for reserves)
+        // before any transfer, following checks-effects-interactions
+        let price_before = token_info.bonding_curve.get_current_price();
+        token_info.bonding_curve.execute_sell(xlm_gross, token_amount)?;
+        let price_after = token_info.bonding_curve.get_current_price();
 
-            let xlm_token_address = Self::get_xlm_token_address(&env);
-            let xlm_client = token::Client::new(&env, &xlm_token_address);
+        // 9. Update total XLM raised (using safe math)
+        token_info.xlm_raised = math::safe_sub(token_info.xlm_raised, xlm_gross)?;
+        let fee_config = fee_management::get_fee_config(&env)?;
+        let xlm_quoted = token_info.quote_asset == Self::get_xlm_token_address(&env);
+        if xlm_quoted {
+            storage::adjust_total_xlm_locked(&env, -xlm_gross)?;
+            storage::adjust_curve_xlm_backing(&env, -xlm_gross)?;
+            // Without XLM fee mode the sell fee stays in the factory
+            if !fee_config.fee_in_xlm {
+                storage::adjust_accrued_xlm_fees(&env, fee_amount)?;
+            }
+        }
+        token_info.total_volume_xlm = math::safe_add(token_info.total_volume_xlm, xlm_gross)?;
+        token_info.fees_collected = math::safe_add(token_info.fees_collected, fee_amount)?;
+        token_info.trade_count = token_info.trade_count.saturating_add(1);
+        if !storage::record_trade_direction(&env, &token, &seller, false) {
+            token_info.organic_volume_xlm = math::safe_add(token_info.organic_volume_xlm, xlm_gross)?;
+        }
 
-            xlm_client.transfer(&contract_address, &seller, &xlm_net);
+        // 10. Update market cap
+        token_info.market_cap = math::safe_mul(token_info.xlm_raised, 2)?;
+
+        // 11. Save state
+        storage::set_token_info(&env, &token, &token_info);
+        if token_info.price_history_enabled {
+            storage::record_price_snapshot(&env, &token, price_after, token_info.xlm_raised);
         }
 
-        // 10. Update bonding curve state (using gross amount for reserves)
-        token_info.bonding_curve.execute_sell(xlm_gross, token_amount)?;
+        // 12. INTERACTIONS: Pull tokens from seller, then pay out the quote asset.
+        // Reserves are already reduced, so a reentrant call sees post-sell state.
+        {
+            let token_client = token::Client::new(&env, &token);
+            let contract_address = env.current_contract_address();
 
-        // 11. Update total XLM raised (using safe math)
-        token_info.xlm_raised = math::safe_sub(token_info.xlm_raised, xlm_gross)?;
+            token_client.transfer(&seller, &contract_address, &token_amount);
 
-        // 12. Update market cap
-        token_info.market_cap = math::safe_mul(token_info.xlm_raised, 2)?;
+            let quote_client = token::Client::new(&env, &token_info.quote_asset);
 
-        // 13. Save state
-        storage::set_token_info(&env, &token, &token_info);
+            quote_client.transfer(&contract_address, &seller, &xlm_net);
+        }
+
+        // Sell fees are always in the quote asset; forward them when XLM fee mode
+        // is on. Only XLM fees are accrued for withdrawal, so fees in any other
+        // quote asset are always forwarded rather than left in the factory
+        if fee_config.fee_in_xlm || !xlm_quoted {
+            fee_management::send_quote_fee_to_treasury(&env, &token_info.quote_asset, fee_amount)?;
+        }
 
-        // 14. Emit event (with net amount)
+        // 13. Emit events (basic with net amount, and detailed)
         events::tokens_sold(&env, &seller, &token, token_amount, xlm_net);
+        events::tokens_sold_detailed(
+            &env,
+            &seller,
+            &token,
+            token_amount,
+            xlm_gross,
+            fee_amount,
+            fee_management::effective_fee_bps(fee_amount, fee_config.sell_fee_bps),
+            xlm_net,
+            price_before,
+            price_after,
+        );
 
         Ok(xlm_net)
     }
 
+    /// Enable or disable sells for a bonding curve token (creator only)
+    ///
+    /// Sells can be paused for at most `MAX_SELLS_DISABLED_DURATION`, after which
+    /// they resume automatically. Every toggle emits a `sells_toggled` event.
+    ///
+    /// # Arguments
+    /// * `creator` - Token creator address
+    /// * `token` - Token address
+    /// * `enabled` - Whether sells are allowed
+    pub fn set_sells_enabled(env: Env, creator: Address, token: Address, enabled: bool) -> Result<(), Error> {
+        creator.require_auth();
+
+        let mut token_info = storage::get_token_info(&env, &token)
+            .ok_or(Error::TokenNotFound)?;
+
+        if token_info.creator != creator {
+            return Err(Error::Unauthorized);
+        }
+
+        if token_info.status != TokenStatus::Bonding {
+            return Err(Error::AlreadyGraduated);
+        }
+
+        let now = env.ledger().timestamp();
+        let resumes_at = if enabled {
+            token_info.sells_disabled_at = 0;
+            0
+        } else {
+            token_info.sells_disabled_at = now;
+            now.saturating_add(MAX_SELLS_DISABLED_DURATION)
+        };
+        token_info.sells_enabled = enabled;
+
+        storage::set_token_info(&env, &token, &token_info);
+
+        events::sells_toggled(&env, &token, &creator, enabled, resumes_at);
+
+        Ok(())
+    }
+
+    /// Update a token's image URL and description (creator only)
+    ///
+    /// Same length limits as `launch_token`. Emits `MetadataUpdated`.
+    pub fn update_metadata(
+        env: Env,
+        creator: Address,
+        token: Address,
+        image_url: String,
+        description: String,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+
+        let mut token_info = storage::get_token_info(&env, &token)
+            .ok_or(Error::TokenNotFound)?;
+
+        if token_info.creator != creator {
+            return Err(Error::Unauthorized);
+        }
+
+        if image_url.len() > MAX_IMAGE_URL_LENGTH || description.len() > MAX_DESCRIPTION_LENGTH {
+            return Err(Error::InvalidMetadata);
+        }
+
+        token_info.image_url = image_url.clone();
+        token_info.description = description.clone();
+        storage::set_token_info(&env, &token, &token_info);
+
+        events::metadata_updated(&env, &token, &image_url, &description, &creator);
+
+        Ok(())
+    }
+
+    /// Hand a token over to a new creator (current creator only)
+    ///
+    /// Moves every creator-gated permission (metadata, sell toggle, allocation
+    /// claims) and re-indexes `get_creator_tokens`. Emits `TokenOwnershipTransferred`.
+    pub fn transfer_token_ownership(
+        env: Env,
+        current_creator: Address,
+        token: Address,
+        new_creator: Address,
+    ) -> Result<(), Error> {
+        current_creator.require_auth();
+
+        let mut token_info = storage::get_token_info(&env, &token)
+            .ok_or(Error::TokenNotFound)?;
+
+        if token_info.creator != current_creator {
+            return Err(Error::Unauthorized);
+        }
+
+        token_info.creator = new_creator.clone();
+        storage::set_token_info(&env, &token, &token_info);
+
+        storage::remove_creator_token(&env, &current_creator, &token);
+        storage::add_creator_token(&env, &new_creator, &token);
+
+        events::token_ownership_transferred(&env, &token, &current_creator, &new_creator);
+
+        Ok(())
+    }
+
+    /// Turn on-chain price history recording on or off for a token (creator only)
+    pub fn set_price_history_enabled(env: Env, creator: Address, token: Address, enabled: bool) -> Result<(), Error> {
+        creator.require_auth();
+
+        let mut token_info = storage::get_token_info(&env, &token)
+            .ok_or(Error::TokenNotFound)?;
+
+        if token_info.creator != creator {
+            return Err(Error::Unauthorized);
+        }
+
+        token_info.price_history_enabled = enabled;
+        storage::set_token_info(&env, &token, &token_info);
+
+        Ok(())
+    }
+
+    /// Get recent price snapshots for charting
+    ///
+    /// # Arguments
+    /// * `token` - Token address
+    /// * `limit` - Max snapshots to return (capped at 64)
+    ///
+    /// # Returns
+    /// Most recent `(timestamp, price, xlm_raised)` snapshots, oldest first
+    pub fn get_price_history(env: Env, token: Address, limit: u32) -> Vec<(u64, i128, i128)> {
+        let history = storage::get_price_history(&env, &token);
+        let len = history.len();
+        let start = len.saturating_sub(limit.min(storage::MAX_PRICE_HISTORY));
+        history.slice(start..len)
+    }
+
     /// Get token information
     pub fn get_token_info(env: Env, token: Address) -> Option<TokenInfo> {
         storage::get_token_info(&env, &token)
     }
 
+    /// Get just a token's lifecycle status (`Bonding` or `Graduated`)
+    ///
+    /// The field UIs poll to choose between curve and AMM controls; returns the
+    /// enum alone instead of the whole `TokenInfo`.
+    pub fn get_token_status(env: Env, token: Address) -> Result<TokenStatus, Error> {
+        storage::get_token_info(&env, &token)
+            .map(|info| info.status)
+            .ok_or(Error::TokenNotFound)
+    }
+
+    /// Check whether a token's record still resolves
+    ///
+    /// `false` for unknown tokens and for tokens whose `TokenInfo` expired (see
+    /// `prune_expired`).
+    pub fn is_token_live(env: Env, token: Address) -> bool {
+        storage::has_token_info(&env, &token)
+    }
+
+    /// Get a token address by its sequential id (`TokenInfo.id`, assigned at launch)
+    ///
+    /// `None` once the token's record has expired.
+    pub fn get_token_by_id(env: Env, id: u32) -> Option<Address> {
+        storage::get_token_by_id(&env, id).filter(|token| storage::has_token_info(&env, token))
+    }
+
     /// Get current price for 1 token (in stroops)
+    ///
+    /// The curve's final price once graduated. `AlreadyGraduated` for tokens
+    /// launched with `launch_to_amm`, which never had a curve: price them on
+    /// the AMM pair.
     pub fn get_price(env: Env, token: Address) -> Result<i128, Error> {
         let token_info = storage::get_token_info(&env, &token)
             .ok_or(Error::TokenNotFound)?;
 
+        if token_info.bonding_curve.is_empty() {
+            return Err(Error::AlreadyGraduated);
+        }
+
         Ok(token_info.bonding_curve.get_current_price())
     }
 
+    /// Get spot and average price for a hypothetical buy in one read
+    ///
+    /// The average price is what `xlm_amount` pays per token received after
+    /// fees, quoted exactly as `buy` would. Both prices share the same scale
+    /// as `get_price`.
+    ///
+    /// # Returns
+    /// `(spot_price, avg_buy_price, price_impact_bps)`
+    pub fn get_prices(env: Env, token: Address, xlm_amount: i128) -> Result<(i128, i128, i128), Error> {
+        if xlm_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let token_info = storage::get_token_info(&env, &token)
+            .ok_or(Error::TokenNotFound)?;
+
+        if token_info.status != TokenStatus::Bonding {
+            return Err(Error::AlreadyGraduated);
+        }
+
+        let spot_price = token_info.bonding_curve.get_current_price();
+        let (_, _, tokens_net, _) = Self::quote_buy(&env, &token_info.bonding_curve, xlm_amount)?;
+        let avg_buy_price = math::mul_div(xlm_amount, bonding_curve::PRECISION, tokens_net)?;
+        let price_impact_bps = math::calculate_slippage_bps(spot_price, avg_buy_price)?;
+
+        Ok((spot_price, avg_buy_price, price_impact_bps))
+    }
+
+    /// Get bonding curve reserves, mirroring the AMM pair's `get_reserves`
+    ///
+    /// `xlm_reserve` includes the (effective, after any decay) virtual reserve, so
+    /// `xlm_reserve / tokens_remaining` is the spot price, just as
+    /// `reserve_0 / reserve_1` is on the pair.
+    ///
+    /// # Returns
+    /// `(xlm_reserve, tokens_remaining, timestamp)` with the current ledger timestamp
+    ///
+    /// # Errors
+    /// `AlreadyGraduated` once the curve has closed; query the AMM pair from
+    /// `get_post_graduation_venue` instead.
+    pub fn get_reserves(env: Env, token: Address) -> Result<(i128, i128, u64), Error> {
+        let token_info = storage::get_token_info(&env, &token)
+            .ok_or(Error::TokenNotFound)?;
+
+        if token_info.status != TokenStatus::Bonding {
+            return Err(Error::AlreadyGraduated);
+        }
+
+        let (xlm_reserve, _) = token_info.bonding_curve.effective_reserves()?;
+
+        Ok((
+            xlm_reserve,
+            token_info.bonding_curve.tokens_remaining,
+            env.ledger().timestamp(),
+        ))
+    }
+
     /// Get graduation progress (0-10000 = 0%-100%)
+    ///
+    /// Always 0 for perpetual tokens (launched with `disable_graduation`) and
+    /// for tokens launched with `launch_to_amm`, which never had a curve.
     pub fn get_graduation_progress(env: Env, token: Address) -> Result<i128, Error> {
         let token_info = storage::get_token_info(&env, &token)
             .ok_or(Error::TokenNotFound)?;
 
-        let progress = token_info.xlm_raised
-            .checked_mul(10_000)
-            .and_then(|v| v.checked_div(GRADUATION_THRESHOLD))
-            .unwrap_or(10_000); // If overflow, assume 100%
-        Ok(progress.min(10_000))
+        if !token_info.graduation_enabled {
+            return Ok(0);
+        }
+
+        Ok(Self::graduation_progress_bps(token_info.xlm_raised))
+    }
+
+    /// Check whether a token graduates to an AMM (false for perpetual curves)
+    pub fn is_graduation_enabled(env: Env, token: Address) -> Result<bool, Error> {
+        let token_info = storage::get_token_info(&env, &token)
+            .ok_or(Error::TokenNotFound)?;
+
+        Ok(token_info.graduation_enabled)
+    }
+
+    /// Simulate a buy and return its full effect without executing it
+    ///
+    /// Uses the same quoting logic as `buy`. Deadline, pause and trading-start
+    /// checks are not applied since nothing is executed.
+    ///
+    /// # Arguments
+    /// * `token` - Token address to buy
+    /// * `xlm_amount` - Amount of XLM to spend (in stroops)
+    pub fn simulate_buy(env: Env, token: Address, xlm_amount: i128) -> Result<BuySimulation, Error> {
+        if xlm_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let token_info = storage::get_token_info(&env, &token)
+            .ok_or(Error::TokenNotFound)?;
+
+        if token_info.status != TokenStatus::Bonding {
+            return Err(Error::AlreadyGraduated);
+        }
+
+        let price_before = token_info.bonding_curve.get_current_price();
+        let (xlm_to_curve, tokens_gross, tokens_net, fee) =
+            Self::quote_buy(&env, &token_info.bonding_curve, xlm_amount)?;
+
+        // Apply the trade to a copy of the curve
+        let mut bonding_curve = token_info.bonding_curve.clone();
+        bonding_curve.execute_buy(xlm_to_curve, tokens_gross)?;
+
+        let price_after = bonding_curve.get_current_price();
+        let slippage_bps = math::calculate_slippage_bps(price_before, price_after)?;
+        let new_xlm_raised = math::safe_add(token_info.xlm_raised, xlm_to_curve)?;
+
+        Ok(BuySimulation {
+            tokens_net,
+            fee,
+            price_after,
+            slippage_bps,
+            new_xlm_raised,
+            new_graduation_progress_bps: if token_info.graduation_enabled {
+                Self::graduation_progress_bps(new_xlm_raised)
+            } else {
+                0
+            },
+            would_graduate: token_info.graduation_enabled
+                && Self::should_graduate(&env, new_xlm_raised, &bonding_curve)?,
+        })
+    }
+
+    /// Preview how a hypothetical trade's value splits into fees and the trade itself
+    ///
+    /// Read-only, using the current `FeeConfig` and curve state so the UI can show a
+    /// fee disclosure before signing.
+    ///
+    /// # Arguments
+    /// * `token` - Token address
+    /// * `amount` - XLM to spend for buys, tokens to sell for sells
+    /// * `is_buy` - Preview a buy (true) or a sell (false)
+    pub fn preview_fees(env: Env, token: Address, amount: i128, is_buy: bool) -> Result<FeeBreakdown, Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let token_info = storage::get_token_info(&env, &token)
+            .ok_or(Error::TokenNotFound)?;
+
+        if token_info.status != TokenStatus::Bonding {
+            return Err(Error::AlreadyGraduated);
+        }
+
+        let fee_config = fee_management::get_fee_config(&env)?;
+        let (gross, fee, net, fee_asset) = if is_buy {
+            let (xlm_to_curve, tokens_gross, tokens_net, fee) =
+                Self::quote_buy(&env, &token_info.bonding_curve, amount)?;
+            if fee_config.fee_in_xlm {
+                (amount, fee, xlm_to_curve, token_info.quote_asset)
+            } else {
+                (tokens_gross, fee, tokens_net, token.clone())
+            }
+        } else {
+            let xlm_gross = token_info.bonding_curve.calculate_sell(amount)?;
+            let (xlm_net, fee) =
+                fee_management::apply_trading_fee(&env, xlm_gross, fee_management::TradeDirection::Sell)?;
+            (xlm_gross, fee, xlm_net, token_info.quote_asset)
+        };
+
+        // Only token-denominated fees are partly burned
+        let (burn_fee, protocol_fee) = if fee_asset == token {
+            fee_management::split_token_fee(gross, fee, fee_config.fee_burn_bps)?
+        } else {
+            (0, fee)
+        };
+
+        Ok(FeeBreakdown {
+            gross,
+            protocol_fee,
+            burn_fee,
+            creator_fee: 0,
+            referral_fee: 0,
+            net,
+            fee_asset,
+        })
+    }
+
+    /// Get platform total value locked, in XLM stroops
+    ///
+    /// Running total of real XLM deposited into XLM-quoted bonding curves (buys add,
+    /// sells subtract). Graduation moves curve XLM into the AMM pair and it keeps
+    /// counting, less the graduation fee and any excess XLM paid to the treasury.
+    /// A `launch_to_amm` pair counts with the creator's seed XLM.
+    /// This tracks deposits only: AMM-side trading, appreciation, virtual
+    /// curve reserves and non-XLM quoted curves are not reflected.
+    pub fn get_tvl(env: Env) -> i128 {
+        storage::get_total_xlm_locked(&env)
     }
 
-    /// Get all tokens by creator (use with caution, may be large)
+    /// Get XLM still needed to push a token over the graduation threshold
+    ///
+    /// Tokens received for that XLM depend on the curve state at the time of the buy.
+    ///
+    /// # Returns
+    /// `max(0, graduation_threshold - xlm_raised)` in stroops. Perpetual tokens
+    /// never graduate however much is raised (see `is_graduation_enabled`).
+    pub fn xlm_to_graduate(env: Env, token: Address) -> Result<i128, Error> {
+        let token_info = storage::get_token_info(&env, &token)
+            .ok_or(Error::TokenNotFound)?;
+
+        if token_info.status != TokenStatus::Bonding {
+            return Err(Error::AlreadyGraduated);
+        }
+
+        Ok(math::safe_sub(GRADUATION_THRESHOLD, token_info.xlm_raised)?.max(0))
+    }
+
+    /// Whether `account` ever bought `token` on its bonding curve
+    ///
+    /// Stays true after the account sells out, so it can gate airdrops or
+    /// holder chats on participation. Buys made with `buy_for` count for the
+    /// recipient, not the payer.
+    pub fn has_traded(env: Env, token: Address, account: Address) -> bool {
+        storage::has_traded(&env, &token, &account)
+    }
+
+    /// Get a creator's first tokens, at most `MAX_PAGE_SIZE` (100)
+    ///
+    /// Deprecated: kept for existing clients, but capped so a prolific creator
+    /// can't make the read exceed resource limits. Use
+    /// `get_creator_tokens_paginated` with `get_creator_token_count` to list
+    /// everything.
     pub fn get_creator_tokens(env: Env, creator: Address) -> Vec<Address> {
-        storage::get_creator_tokens(&env, &creator)
+        storage::get_creator_tokens_paginated(&env, &creator, 0, storage::MAX_PAGE_SIZE)
     }
 
     /// Get creator's tokens with pagination (recommended for large lists)
@@ -469,6 +1212,80 @@ impl SacFactory {
         storage::get_creator_tokens_paginated(&env, &creator, offset, limit)
     }
 
+    /// Get creator's unclaimed graduation allocation for a token
+    pub fn get_creator_claimable(env: Env, token: Address) -> i128 {
+        storage::get_creator_claimable(&env, &token)
+    }
+
+    /// Claim the creator's graduation token allocation
+    ///
+    /// # Arguments
+    /// * `creator` - Token creator address
+    /// * `token` - Graduated token address
+    ///
+    /// # Returns
+    /// Amount of tokens claimed (0 once a graduated token's allocation has been claimed)
+    pub fn claim_creator_allocation(env: Env, creator: Address, token: Address) -> Result<i128, Error> {
+        creator.require_auth();
+
+        let token_info = storage::get_token_info(&env, &token)
+            .ok_or(Error::TokenNotFound)?;
+
+        if token_info.creator != creator {
+            return Err(Error::Unauthorized);
+        }
+
+        let amount = storage::get_creator_claimable(&env, &token);
+        if amount <= 0 {
+            if token_info.status == TokenStatus::Graduated {
+                return Ok(0);
+            }
+            return Err(Error::InsufficientBalance);
+        }
+
+        // Zero the claimable balance before transferring out
+        storage::set_creator_claimable(&env, &token, 0);
+
+        let token_client = token::Client::new(&env, &token);
+        token_client.transfer(&env.current_contract_address(), &creator, &amount);
+
+        events::creator_allocation_claimed(&env, &creator, &token, amount);
+
+        Ok(amount)
+    }
+
+    /// Get number of tokens launched by a creator (for pagination bounds)
+    pub fn get_creator_token_count(env: Env, creator: Address) -> u32 {
+        storage::get_creator_tokens(&env, &creator).len()
+    }
+
+    /// Get a page of creator's tokens together with the creator's total token count
+    ///
+    /// # Returns
+    /// Tuple of (paginated token addresses, total count)
+    pub fn get_creator_tokens_page(
+        env: Env,
+        creator: Address,
+        offset: u32,
+        limit: u32,
+    ) -> (Vec<Address>, u32) {
+        let page = storage::get_creator_tokens_paginated(&env, &creator, offset, limit);
+        let total = storage::get_creator_tokens(&env, &creator).len();
+        (page, total)
+    }
+
+    /// Get graduated tokens with pagination (tokens trading on their AMM pair)
+    ///
+    /// # Arguments
+    /// * `offset` - Starting index
+    /// * `limit` - Max items to return (capped at 100)
+    ///
+    /// # Returns
+    /// Paginated list of graduated token addresses, in graduation order
+    pub fn get_graduated_tokens_paginated(env: Env, offset: u32, limit: u32) -> Vec<Address> {
+        storage::get_graduated_tokens_paginated(&env, offset, limit)
+    }
+
     /// Get total tokens created
     pub fn get_token_count(env: Env) -> u32 {
         storage::get_token_count(&env)
@@ -476,14 +1293,34 @@ impl SacFactory {
 
     // ========== Admin Functions ==========
 
-    /// Pause the contract (PauseAdmin, EmergencyPauser, or Owner)
-    pub fn pause(env: Env, admin: Address) -> Result<(), Error> {
-        state_management::pause(&env, &admin)
+    /// Pause the contract (PauseAdmin, EmergencyPauser, or Owner)
+    pub fn pause(env: Env, admin: Address) -> Result<(), Error> {
+        state_management::pause(&env, &admin)
+    }
+
+    /// Unpause the contract (Owner or PauseAdmin only)
+    pub fn unpause(env: Env, admin: Address) -> Result<(), Error> {
+        state_management::unpause(&env, &admin)
+    }
+
+    /// Pause or resume buys and sells only (Owner or PauseAdmin)
+    pub fn set_trading_paused(env: Env, admin: Address, paused: bool) -> Result<(), Error> {
+        state_management::set_trading_paused(&env, &admin, paused)
+    }
+
+    /// Pause or resume token launches only (Owner or PauseAdmin)
+    pub fn set_launches_paused(env: Env, admin: Address, paused: bool) -> Result<(), Error> {
+        state_management::set_launches_paused(&env, &admin, paused)
+    }
+
+    /// Check if trading is paused (independent of the global pause)
+    pub fn is_trading_paused(env: Env) -> bool {
+        state_management::is_trading_paused(&env)
     }
 
-    /// Unpause the contract (Owner or PauseAdmin only)
-    pub fn unpause(env: Env, admin: Address) -> Result<(), Error> {
-        state_management::unpause(&env, &admin)
+    /// Check if launches are paused (independent of the global pause)
+    pub fn is_launches_paused(env: Env) -> bool {
+        state_management::is_launches_paused(&env)
     }
 
     /// Grant a role to an address (Owner only)
@@ -491,6 +1328,11 @@ impl SacFactory {
         access_control::grant_role(&env, &granter, &account, role)
     }
 
+    /// Grant several roles at once, all or nothing (Owner only, up to `MAX_ROLE_GRANTS`)
+    pub fn grant_roles(env: Env, granter: Address, grants: Vec<(Address, access_control::Role)>) -> Result<(), Error> {
+        access_control::grant_roles(&env, &granter, &grants)
+    }
+
     /// Revoke a role from an address (Owner only)
     pub fn revoke_role(env: Env, revoker: Address, account: Address, role: access_control::Role) -> Result<(), Error> {
         access_control::revoke_role(&env, &revoker, &account, role)
@@ -501,16 +1343,171 @@ impl SacFactory {
         access_control::transfer_ownership(&env, &current_owner, &new_owner)
     }
 
-    /// Update fee configuration (FeeAdmin or Owner)
+    /// Update fee configuration, charging `trading_fee_bps` on buys and sells alike
+    /// (FeeAdmin or Owner)
     pub fn update_fees(env: Env, admin: Address, creation_fee: i128, trading_fee_bps: i128) -> Result<(), Error> {
         fee_management::set_fee_config(&env, &admin, creation_fee, trading_fee_bps)
     }
 
+    /// Set separate buy and sell fee rates (FeeAdmin or Owner)
+    ///
+    /// Each rate is capped at `MAX_TRADING_FEE_BPS`; `fee_burn_bps` must not exceed
+    /// the buy rate.
+    pub fn update_trading_fees(env: Env, admin: Address, buy_fee_bps: i128, sell_fee_bps: i128) -> Result<(), Error> {
+        fee_management::set_trading_fees(&env, &admin, buy_fee_bps, sell_fee_bps)
+    }
+
+    /// Update creator share of graduation token liquidity (FeeAdmin or Owner)
+    ///
+    /// # Arguments
+    /// * `admin` - FeeAdmin or Owner address
+    /// * `graduation_creator_bps` - Share of remaining curve tokens carved out
+    ///   for the creator at graduation (max 1000 = 10%)
+    pub fn set_graduation_creator_bps(env: Env, admin: Address, graduation_creator_bps: i128) -> Result<(), Error> {
+        fee_management::set_graduation_creator_bps(&env, &admin, graduation_creator_bps)
+    }
+
+    /// Update the graduation fee (FeeAdmin or Owner)
+    ///
+    /// # Arguments
+    /// * `admin` - FeeAdmin or Owner address
+    /// * `graduation_fee_bps` - Share of the raised XLM sent to the treasury at
+    ///   graduation instead of the pool (max 1000 = 10%)
+    pub fn set_graduation_fee_bps(env: Env, admin: Address, graduation_fee_bps: i128) -> Result<(), Error> {
+        fee_management::set_graduation_fee_bps(&env, &admin, graduation_fee_bps)
+    }
+
+    /// Choose the buy fee currency (FeeAdmin or Owner)
+    ///
+    /// When `fee_in_xlm` is true, buys pay the fee out of the XLM input (forwarded to
+    /// the treasury) and receive the full curve output. Otherwise the fee is withheld
+    /// from the tokens. Sell fees are always taken in the quote asset; when false,
+    /// XLM sell fees accrue for `request_withdrawal` while other quote assets'
+    /// sell fees still go straight to the treasury.
+    pub fn set_fee_in_xlm(env: Env, admin: Address, fee_in_xlm: bool) -> Result<(), Error> {
+        fee_management::set_fee_in_xlm(&env, &admin, fee_in_xlm)
+    }
+
+    /// Burn part of token-denominated trading fees (FeeAdmin or Owner)
+    ///
+    /// `fee_burn_bps` is taken from the gross token amount of each buy and must not
+    /// exceed `buy_fee_bps`; the rest of the fee goes to the treasury. Has no
+    /// effect while `fee_in_xlm` is set, since those fees are not in the token.
+    pub fn set_fee_burn_bps(env: Env, admin: Address, fee_burn_bps: i128) -> Result<(), Error> {
+        fee_management::set_fee_burn_bps(&env, &admin, fee_burn_bps)
+    }
+
+    /// Waive the creation fee for launches with a large dev buy (FeeAdmin or Owner)
+    ///
+    /// # Arguments
+    /// * `threshold` - Minimum `initial_buy_xlm` (in stroops) that launches for
+    ///   free (0 = always charge the fee)
+    pub fn set_dev_buy_fee_waiver_threshold(env: Env, admin: Address, threshold: i128) -> Result<(), Error> {
+        fee_management::set_dev_buy_fee_waiver_threshold(&env, &admin, threshold)
+    }
+
+    /// Split fees across several recipients (TreasuryAdmin or Owner)
+    ///
+    /// # Arguments
+    /// * `recipients` - `(recipient, bps)` pairs summing to 10000, at most
+    ///   `MAX_FEE_RECIPIENTS` (empty = send all fees to the treasury)
+    ///
+    /// Applies to creation fees and trading fees; the last recipient receives
+    /// any rounding remainder.
+    pub fn set_fee_recipients(env: Env, admin: Address, recipients: Vec<(Address, u32)>) -> Result<(), Error> {
+        fee_management::set_fee_recipients(&env, &admin, &recipients)
+    }
+
+    /// Get the fee split (empty when all fees go to the treasury)
+    pub fn get_fee_recipients(env: Env) -> Vec<(Address, u32)> {
+        fee_management::get_fee_recipients(&env)
+    }
+
     /// Update treasury address (TreasuryAdmin or Owner)
     pub fn update_treasury(env: Env, admin: Address, new_treasury: Address) -> Result<(), Error> {
         fee_management::set_treasury(&env, &admin, &new_treasury)
     }
 
+    /// Sweep the factory's residual balance of a graduated token to treasury
+    /// (TreasuryAdmin or Owner)
+    ///
+    /// Bonding tokens are rejected so active curves can never be drained, and
+    /// unclaimed creator allocations are left in place.
+    ///
+    /// # Returns
+    /// Amount of tokens swept
+    pub fn sweep_token(env: Env, admin: Address, token: Address) -> Result<i128, Error> {
+        admin.require_auth();
+
+        if !access_control::has_role(&env, &admin, access_control::Role::TreasuryAdmin)
+            && !access_control::has_role(&env, &admin, access_control::Role::Owner) {
+            return Err(Error::Unauthorized);
+        }
+
+        let token_info = storage::get_token_info(&env, &token)
+            .ok_or(Error::TokenNotFound)?;
+
+        if token_info.status != TokenStatus::Graduated {
+            return Err(Error::NotGraduated);
+        }
+
+        let token_client = token::Client::new(&env, &token);
+        let contract_address = env.current_contract_address();
+
+        // Keep the creator's unclaimed allocation
+        let balance = token_client.balance(&contract_address);
+        let claimable = storage::get_creator_claimable(&env, &token);
+        let amount = math::safe_sub(balance, claimable)?;
+
+        if amount <= 0 {
+            return Err(Error::InsufficientBalance);
+        }
+
+        let treasury = fee_management::get_fee_config(&env)?.treasury;
+        token_client.transfer(&contract_address, &treasury, &amount);
+
+        events::token_swept(&env, &token, &treasury, amount, &admin);
+
+        Ok(amount)
+    }
+
+    /// Request a withdrawal of accrued XLM sell fees to treasury (TreasuryAdmin or Owner)
+    ///
+    /// Below the instant threshold the fees are paid out right away; otherwise the
+    /// request waits out the withdrawal delay (default 24h) before
+    /// `execute_withdrawal` can pay it. One request may be pending at a time.
+    pub fn request_withdrawal(env: Env, admin: Address, amount: i128) -> Result<(), Error> {
+        fee_management::request_withdrawal(&env, &Self::get_xlm_token_address(&env), &admin, amount)
+    }
+
+    /// Pay out the pending withdrawal once its delay has passed (TreasuryAdmin or Owner)
+    ///
+    /// # Returns
+    /// Amount withdrawn
+    pub fn execute_withdrawal(env: Env, admin: Address) -> Result<i128, Error> {
+        fee_management::execute_withdrawal(&env, &Self::get_xlm_token_address(&env), &admin)
+    }
+
+    /// Drop the pending withdrawal (TreasuryAdmin or Owner)
+    pub fn cancel_withdrawal(env: Env, admin: Address) -> Result<(), Error> {
+        fee_management::cancel_withdrawal(&env, &admin)
+    }
+
+    /// Get the withdrawal waiting out its delay, if any
+    pub fn get_pending_withdrawal(env: Env) -> Option<fee_management::PendingWithdrawal> {
+        fee_management::get_pending_withdrawal(&env)
+    }
+
+    /// Set the withdrawal delay and the amount below which withdrawals skip it (Owner only)
+    pub fn set_withdrawal_timelock(env: Env, admin: Address, delay: u64, instant_threshold: i128) -> Result<(), Error> {
+        fee_management::set_withdrawal_timelock(&env, &admin, delay, instant_threshold)
+    }
+
+    /// Get the withdrawal timelock settings
+    pub fn get_withdrawal_timelock(env: Env) -> fee_management::WithdrawalTimelock {
+        fee_management::get_withdrawal_timelock(&env)
+    }
+
     /// Set AMM pair WASM hash for graduation (Owner only)
     ///
     /// # Arguments
@@ -530,6 +1527,17 @@ impl SacFactory {
         Ok(())
     }
 
+    /// Get the AMM WASM hash used for graduation deployments
+    ///
+    /// Lets anyone compare the stored hash against the published AMM WASM to
+    /// confirm graduated liquidity lands in the expected contract.
+    ///
+    /// # Returns
+    /// The WASM hash, or None if not configured
+    pub fn get_amm_wasm_hash(env: Env) -> Option<soroban_sdk::BytesN<32>> {
+        storage::get_amm_wasm_hash(&env)
+    }
+
     /// Get AMM pair address for a graduated token
     ///
     /// # Arguments
@@ -543,6 +1551,89 @@ impl SacFactory {
             .get(&storage::PersistentKey::AmmPairAddress(token))
     }
 
+    /// Predict the AMM pair address a token will graduate into
+    ///
+    /// The pair is deployed deterministically from the quote asset (XLM unless
+    /// chosen at launch) and token addresses, so frontends and arbitrageurs can
+    /// watch it before graduation fires.
+    ///
+    /// # Returns
+    /// The future pair address, or `AmmWasmNotSet` if no AMM WASM is configured
+    pub fn predict_amm_pair(env: Env, token: Address) -> Result<Address, Error> {
+        let quote_asset = storage::get_token_info(&env, &token)
+            .map(|token_info| token_info.quote_asset)
+            .unwrap_or_else(|| Self::get_xlm_token_address(&env));
+        amm_deployment::get_amm_pair_address(&env, &quote_asset, &token)
+    }
+
+    /// Check if a token has left the bonding curve
+    ///
+    /// `buy` and `sell` both return `AlreadyGraduated` once this is true, so
+    /// frontends should gate their curve buy/sell buttons on it and route trades
+    /// to `get_post_graduation_venue` instead.
+    ///
+    /// # Returns
+    /// true if graduated, false if still bonding or unknown
+    pub fn is_graduated(env: Env, token: Address) -> bool {
+        storage::get_token_info(&env, &token)
+            .map(|token_info| token_info.status == TokenStatus::Graduated)
+            .unwrap_or(false)
+    }
+
+    /// Get where a token trades after leaving the bonding curve
+    ///
+    /// Wallets should route "sell" to this AMM pair once `sell` returns `AlreadyGraduated`.
+    ///
+    /// # Returns
+    /// AMM pair address if the token has graduated, None while it is still bonding
+    pub fn get_post_graduation_venue(env: Env, token: Address) -> Option<Address> {
+        let token_info = storage::get_token_info(&env, &token)?;
+
+        if token_info.status != TokenStatus::Graduated {
+            return None;
+        }
+
+        Self::get_amm_pair(env, token)
+    }
+
+    /// Compare a graduated token's final curve price with its AMM price
+    ///
+    /// The curve is frozen at graduation, so its spot price is the last price
+    /// buyers paid. The AMM price is read from the pair's current reserves.
+    ///
+    /// # Returns
+    /// `(curve_final_price, amm_price, gap_bps)`, with `gap_bps` positive when
+    /// the AMM trades above the curve. `NotGraduated` for tokens still bonding,
+    /// `InvalidState` for tokens launched with `launch_to_amm` (no curve).
+    pub fn get_graduation_arb(env: Env, token: Address) -> Result<(i128, i128, i128), Error> {
+        let token_info = storage::get_token_info(&env, &token)
+            .ok_or(Error::TokenNotFound)?;
+
+        if token_info.status != TokenStatus::Graduated {
+            return Err(Error::NotGraduated);
+        }
+        if token_info.bonding_curve.is_empty() {
+            return Err(Error::InvalidState);
+        }
+
+        let amm_address = Self::get_amm_pair(env.clone(), token.clone())
+            .ok_or(Error::NotGraduated)?;
+        let (reserve_0, reserve_1, _) = amm_client::AmmPairClient::new(&env, amm_address).get_reserves()?;
+
+        // The pair sorts its tokens by address
+        let (quote_reserve, token_reserve) = if amm_deployment::quote_is_token_0(&token_info.quote_asset, &token) {
+            (reserve_0, reserve_1)
+        } else {
+            (reserve_1, reserve_0)
+        };
+
+        let curve_final_price = token_info.bonding_curve.get_current_price();
+        let amm_price = math::mul_div(quote_reserve, bonding_curve::PRECISION, token_reserve)?;
+        let gap_bps = math::calculate_slippage_bps(curve_final_price, amm_price)?;
+
+        Ok((curve_final_price, amm_price, gap_bps))
+    }
+
     /// Set DIA Oracle address for price feeds (Owner only)
     ///
     /// # Arguments
@@ -573,13 +1664,271 @@ impl SacFactory {
     pub fn set_min_market_cap_usd(env: Env, admin: Address, min_market_cap_usd: u128) -> Result<(), Error> {
         admin.require_auth();
 
-        // Only owner can configure minimum market cap
-        access_control::require_role(&env, &admin, access_control::Role::Owner)?;
+        // Only owner can configure minimum market cap
+        access_control::require_role(&env, &admin, access_control::Role::Owner)?;
+
+        // Store minimum market cap
+        storage::set_min_market_cap_usd(&env, min_market_cap_usd);
+
+        Ok(())
+    }
+
+    /// Set the share of curve supply sold that forces graduation (Owner only)
+    ///
+    /// # Arguments
+    /// * `admin` - Owner address
+    /// * `max_supply_sold_bps` - Sold share of the token's curve supply (1-10000 bps)
+    ///   above which graduation fires regardless of XLM raised
+    pub fn set_max_supply_sold_bps(env: Env, admin: Address, max_supply_sold_bps: i128) -> Result<(), Error> {
+        admin.require_auth();
+
+        // Only owner can configure graduation triggers
+        access_control::require_role(&env, &admin, access_control::Role::Owner)?;
+
+        if !(1..=10_000).contains(&max_supply_sold_bps) {
+            return Err(Error::InvalidAmount);
+        }
+
+        storage::set_max_supply_sold_bps(&env, max_supply_sold_bps);
+
+        Ok(())
+    }
+
+    /// Set how far the AMM opening price may drift from the curve price (Owner only)
+    ///
+    /// # Arguments
+    /// * `admin` - Owner address
+    /// * `tolerance_bps` - Max gap between AMM seed price and curve spot price
+    ///   (1-10000 bps, default 200 = 2%); graduation reverts beyond it
+    pub fn set_graduation_price_tolerance(env: Env, admin: Address, tolerance_bps: i128) -> Result<(), Error> {
+        admin.require_auth();
+
+        // Only owner can configure graduation safety checks
+        access_control::require_role(&env, &admin, access_control::Role::Owner)?;
+
+        if !(1..=10_000).contains(&tolerance_bps) {
+            return Err(Error::InvalidAmount);
+        }
+
+        storage::set_graduation_price_tolerance_bps(&env, tolerance_bps);
+
+        Ok(())
+    }
+
+    /// Set how far in the future a trade deadline may be (Owner only)
+    ///
+    /// # Arguments
+    /// * `admin` - Owner address
+    /// * `max_deadline_window` - Max seconds past now for `buy`/`sell` deadlines
+    ///   (0 disables the check)
+    pub fn set_max_deadline_window(env: Env, admin: Address, max_deadline_window: u64) -> Result<(), Error> {
+        admin.require_auth();
+
+        access_control::require_role(&env, &admin, access_control::Role::Owner)?;
+
+        storage::set_max_deadline_window(&env, max_deadline_window);
+
+        Ok(())
+    }
+
+    /// Set the smallest sell allowed on bonding curves (Owner only)
+    ///
+    /// # Arguments
+    /// * `admin` - Owner address
+    /// * `min_sell_xlm` - Minimum gross quote-asset output of a sell, in stroops
+    ///   (default 0.01 XLM, 0 disables the floor); smaller sells fail with `AmountTooSmall`
+    pub fn set_min_sell(env: Env, admin: Address, min_sell_xlm: i128) -> Result<(), Error> {
+        admin.require_auth();
+
+        access_control::require_role(&env, &admin, access_control::Role::Owner)?;
+
+        if min_sell_xlm < 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        storage::set_min_sell_xlm(&env, min_sell_xlm);
+
+        Ok(())
+    }
+
+    /// Set when reading a token record renews its storage TTL (Owner only)
+    ///
+    /// A read that finds less than `ledgers` of TTL left extends the record to
+    /// 30 days; reads above the threshold cost nothing extra. Raise it to keep
+    /// idle tokens alive longer, or pass 0 to renew only on writes.
+    ///
+    /// # Arguments
+    /// * `admin` - Owner address
+    /// * `ledgers` - Renewal threshold in ledgers (default 120,960 = 7 days,
+    ///   at most 518,400 = 30 days)
+    pub fn set_read_ttl_threshold(env: Env, admin: Address, ledgers: u32) -> Result<(), Error> {
+        admin.require_auth();
+
+        access_control::require_role(&env, &admin, access_control::Role::Owner)?;
+
+        if ledgers > storage::TOKEN_INFO_TTL {
+            return Err(Error::InvalidAmount);
+        }
+
+        storage::set_read_ttl_threshold(&env, ledgers);
+
+        Ok(())
+    }
+
+    /// Set the protocol fee recipient for AMM pairs deployed at graduation (Owner only)
+    ///
+    /// Lets AMM trading-fee revenue go somewhere other than the bonding-curve
+    /// fee treasury (e.g. an LP incentive pool). Until set, pairs use the treasury.
+    /// Pairs already deployed keep their `fee_to`.
+    pub fn set_amm_fee_to(env: Env, admin: Address, amm_fee_to: Address) -> Result<(), Error> {
+        admin.require_auth();
+
+        access_control::require_role(&env, &admin, access_control::Role::Owner)?;
+
+        storage::set_amm_fee_to(&env, &amm_fee_to);
+
+        Ok(())
+    }
+
+    /// Turn the strict launch charset on or off (Owner only)
+    ///
+    /// When on (the default), names must be printable ASCII and symbols
+    /// uppercase alphanumeric. Turn off to allow unicode names and symbols.
+    pub fn set_strict_charset(env: Env, admin: Address, strict: bool) -> Result<(), Error> {
+        admin.require_auth();
+
+        access_control::require_role(&env, &admin, access_control::Role::Owner)?;
+
+        storage::set_strict_charset(&env, strict);
+
+        Ok(())
+    }
+
+    /// Turn the launch whitelist on or off (Owner only)
+    ///
+    /// When on, only creators added with `set_launch_allowed` can launch; others
+    /// fail with `NotWhitelisted`. Off (the default) lets anyone launch.
+    pub fn set_launch_whitelist_enabled(env: Env, admin: Address, enabled: bool) -> Result<(), Error> {
+        admin.require_auth();
+
+        access_control::require_role(&env, &admin, access_control::Role::Owner)?;
+
+        storage::set_launch_whitelist_enabled(&env, enabled);
+
+        Ok(())
+    }
+
+    /// Add or remove a creator from the launch allowlist (Owner only)
+    ///
+    /// Only consulted while the launch whitelist is on.
+    pub fn set_launch_allowed(env: Env, admin: Address, account: Address, allowed: bool) -> Result<(), Error> {
+        admin.require_auth();
+
+        access_control::require_role(&env, &admin, access_control::Role::Owner)?;
+
+        storage::set_launch_allowed(&env, &account, allowed);
+
+        Ok(())
+    }
+
+    /// Check if an address is on the launch allowlist
+    pub fn is_launch_allowed(env: Env, account: Address) -> bool {
+        storage::is_launch_allowed(&env, &account)
+    }
+
+    /// Rewrite a token record stored in an older `TokenInfo` layout (Owner only)
+    ///
+    /// Fields the old layout lacks get the defaults a plain `launch_token` would
+    /// have set (XLM quote, sells enabled, no launch delay, zeroed counters).
+    /// Records already in the current layout are left untouched.
+    ///
+    /// `get_migration_version` moves to the current layout only once every
+    /// token id (up to `token_count`) was launched or migrated in it.
+    ///
+    /// # Returns
+    /// `true` if the record was rewritten, `false` if it was already current
+    pub fn migrate_token(env: Env, admin: Address, token: Address) -> Result<bool, Error> {
+        admin.require_auth();
+
+        access_control::require_role(&env, &admin, access_control::Role::Owner)?;
+
+        let migrated = match storage::get_legacy_token_info(&env, &token)? {
+            Some(legacy) => {
+                let token_info = legacy.upgrade(Self::get_xlm_token_address(&env));
+                storage::set_token_info(&env, &token, &token_info);
+                storage::set_token_by_id(&env, token_info.id, &token);
+                storage::count_current_layout_record(&env);
+                true
+            }
+            None => false,
+        };
+
+        if storage::get_current_layout_count(&env) >= storage::get_token_count(&env) {
+            storage::set_migration_version(&env, storage::TOKEN_INFO_VERSION);
+        }
+
+        Ok(migrated)
+    }
+
+    /// Drop expired tokens from the enumeration indexes (Owner only)
+    ///
+    /// A token that never trades stops extending its `TokenInfo` TTL, and once it
+    /// expires the indexes still point at an address that no longer resolves.
+    /// Each expired token is removed from its creator's `get_creator_tokens`
+    /// list, from `get_token_by_id` and from the graduated list, found through
+    /// its reverse index (which outlives the record). Live tokens are skipped.
+    ///
+    /// # Arguments
+    /// * `tokens` - Up to `MAX_PRUNE_BATCH` token addresses
+    ///
+    /// # Returns
+    /// Number of tokens pruned
+    pub fn prune_expired(env: Env, admin: Address, tokens: Vec<Address>) -> Result<u32, Error> {
+        admin.require_auth();
+
+        access_control::require_role(&env, &admin, access_control::Role::Owner)?;
+
+        if tokens.len() > storage::MAX_PRUNE_BATCH {
+            return Err(Error::BatchTooLarge);
+        }
+
+        let mut pruned = 0;
+        for token in tokens.iter() {
+            if storage::has_token_info(&env, &token) {
+                continue;
+            }
+            let Some((id, creator)) = storage::get_token_index(&env, &token) else {
+                continue;
+            };
+
+            storage::remove_creator_token(&env, &creator, &token);
+            if storage::get_token_by_id(&env, id) == Some(token.clone()) {
+                storage::remove_token_by_id(&env, id);
+            }
+            storage::remove_graduated_token(&env, &token);
+            storage::remove_token_index(&env, &token);
+            pruned += 1;
+        }
+
+        Ok(pruned)
+    }
 
-        // Store minimum market cap
-        storage::set_min_market_cap_usd(&env, min_market_cap_usd);
+    /// Get the contract version of this code
+    ///
+    /// Clients compare it to the version they were built against before
+    /// calling functions whose signatures may have changed.
+    pub fn version(_env: Env) -> u32 {
+        CONTRACT_VERSION
+    }
 
-        Ok(())
+    /// Get the contract version recorded at initialize (0 if older than the marker)
+    pub fn get_deployed_version(env: Env) -> u32 {
+        storage::get_deployed_version(&env)
+    }
+
+    /// Get the `TokenInfo` layout version every stored token record is on
+    pub fn get_migration_version(env: Env) -> u32 {
+        storage::get_migration_version(&env)
     }
 
     /// Get oracle configuration
@@ -599,15 +1948,97 @@ impl SacFactory {
     }
 
     /// Get fee configuration
-    pub fn get_fee_config(env: Env) -> fee_management::FeeConfig {
+    pub fn get_fee_config(env: Env) -> Result<fee_management::FeeConfig, Error> {
         fee_management::get_fee_config(&env)
     }
 
+    /// Get the current token creation fee (in stroops)
+    pub fn get_creation_fee(env: Env) -> Result<i128, Error> {
+        Ok(fee_management::get_fee_config(&env)?.creation_fee)
+    }
+
+    /// Check the factory's XLM accounting for auditors and monitors
+    ///
+    /// Compares the native XLM balance against what the factory owes: real XLM
+    /// backing XLM-quoted curves still bonding, plus sell fees it holds. Reads
+    /// maintained aggregates only, so the cost does not grow with the token count.
+    /// Alert when `balance_covers_liabilities` or `aggregates_non_negative` is false.
+    ///
+    /// XLM only: curves quoted in another asset (e.g. USDC) are left out on both
+    /// sides, so their balances are neither counted nor checked here.
+    pub fn check_health(env: Env) -> HealthReport {
+        let curve_xlm_backing = storage::get_curve_xlm_backing(&env);
+        let accrued_fees = storage::get_accrued_xlm_fees(&env);
+        let liabilities = curve_xlm_backing.saturating_add(accrued_fees);
+
+        let xlm_balance = token::Client::new(&env, &Self::get_xlm_token_address(&env))
+            .balance(&env.current_contract_address());
+
+        let total_tokens = storage::get_token_count(&env);
+        let total_graduated = storage::get_graduated_count(&env);
+
+        HealthReport {
+            xlm_balance,
+            curve_xlm_backing,
+            accrued_fees,
+            balance_covers_liabilities: xlm_balance >= liabilities,
+            aggregates_non_negative: curve_xlm_backing >= 0
+                && accrued_fees >= 0
+                && storage::get_total_xlm_locked(&env) >= 0
+                && total_graduated <= total_tokens,
+            total_tokens,
+            total_active_tokens: total_tokens.saturating_sub(total_graduated),
+            total_graduated,
+        }
+    }
+
+    /// Get a one-shot snapshot of the factory configuration
+    pub fn get_config(env: Env) -> Result<FactoryConfig, Error> {
+        if !storage::has_admin(&env) {
+            return Err(Error::NotInitialized);
+        }
+
+        let fee_config = fee_management::get_fee_config(&env)?;
+
+        Ok(FactoryConfig {
+            admin: storage::get_admin(&env),
+            treasury: fee_config.treasury,
+            creation_fee: fee_config.creation_fee,
+            buy_fee_bps: fee_config.buy_fee_bps,
+            sell_fee_bps: fee_config.sell_fee_bps,
+            fee_in_xlm: fee_config.fee_in_xlm,
+            fee_burn_bps: fee_config.fee_burn_bps,
+            dev_buy_fee_waiver_threshold: fee_config.dev_buy_fee_waiver_threshold,
+            graduation_fee_bps: fee_config.graduation_fee_bps,
+            graduation_threshold: GRADUATION_THRESHOLD,
+            state: state_management::get_state(&env),
+            trading_paused: state_management::is_trading_paused(&env),
+            launches_paused: state_management::is_launches_paused(&env),
+            oracle: storage::get_oracle_address(&env),
+            amm_fee_to: Self::amm_fee_to(&env)?,
+            min_market_cap_usd: storage::get_min_market_cap_usd(&env),
+            max_supply_sold_bps: storage::get_max_supply_sold_bps(&env),
+            graduation_price_tolerance_bps: storage::get_graduation_price_tolerance_bps(&env),
+            max_deadline_window: storage::get_max_deadline_window(&env),
+            min_sell_xlm: storage::get_min_sell_xlm(&env),
+            strict_charset: storage::get_strict_charset(&env),
+            launch_whitelist_enabled: storage::get_launch_whitelist_enabled(&env),
+            read_ttl_threshold: storage::get_read_ttl_threshold(&env),
+            amm_wasm_set: storage::has_amm_wasm_hash(&env),
+            token_count: storage::get_token_count(&env),
+        })
+    }
+
     /// Check if address has a specific role
     pub fn has_role(env: Env, account: Address, role: access_control::Role) -> bool {
         access_control::has_role(&env, &account, role)
     }
 
+    /// List every role an address holds (empty if none)
+    pub fn get_roles(env: Env, account: Address) -> Vec<access_control::Role> {
+        access_control::get_roles(&env, &account)
+    }
+
     // ========== Internal Functions ==========
 
     /// Validate that an address is not a zero or test address
@@ -639,6 +2070,312 @@ impl SacFactory {
         Ok(())
     }
 
+    /// Require that the token's anti-snipe launch delay has elapsed
+    fn require_trading_started(env: &Env, token_info: &TokenInfo) -> Result<(), Error> {
+        let trading_starts_at = token_info.created_at.saturating_add(token_info.trading_start_delay);
+        if env.ledger().timestamp() < trading_starts_at {
+            return Err(Error::TradingNotStarted);
+        }
+        Ok(())
+    }
+
+    /// Require that sells are enabled, or that the creator's sell pause has expired
+    fn require_sells_enabled(env: &Env, token_info: &TokenInfo) -> Result<(), Error> {
+        if token_info.sells_enabled {
+            return Ok(());
+        }
+        let sells_resume_at = token_info.sells_disabled_at.saturating_add(MAX_SELLS_DISABLED_DURATION);
+        if env.ledger().timestamp() < sells_resume_at {
+            return Err(Error::SellsDisabled);
+        }
+        Ok(())
+    }
+
+    /// Quote a buy against the bonding curve without changing state
+    ///
+    /// Shared by `process_buy` and `simulate_buy` so simulations match execution.
+    /// - fee_in_xlm: fee comes out of the XLM input, buyer gets the full curve output
+    /// - otherwise: full XLM goes into the curve, fee is withheld from the tokens
+    ///
+    /// # Returns
+    /// Tuple of (xlm_to_curve, tokens_gross, tokens_net, fee_amount)
+    fn quote_buy(
+        env: &Env,
+        bonding_curve: &BondingCurve,
+        xlm_amount: i128,
+    ) -> Result<(i128, i128, i128, i128), Error> {
+        let (xlm_to_curve, tokens_gross, tokens_net, fee_amount) =
+            if fee_management::get_fee_config(env)?.fee_in_xlm {
+                let (xlm_net, xlm_fee) =
+                    fee_management::apply_trading_fee(env, xlm_amount, fee_management::TradeDirection::Buy)?;
+                let tokens_out = bonding_curve.calculate_buy(xlm_net)?;
+                (xlm_net, tokens_out, tokens_out, xlm_fee)
+            } else {
+                let tokens_gross = bonding_curve.calculate_buy(xlm_amount)?;
+                let (tokens_net, token_fee) =
+                    fee_management::apply_trading_fee(env, tokens_gross, fee_management::TradeDirection::Buy)?;
+                (xlm_amount, tokens_gross, tokens_net, token_fee)
+            };
+
+        // Never take XLM for zero tokens
+        if tokens_net <= 0 {
+            return Err(Error::AmountTooSmall);
+        }
+
+        Ok((xlm_to_curve, tokens_gross, tokens_net, fee_amount))
+    }
+
+    /// Check a trade deadline is neither past nor beyond the configured window
+    ///
+    /// A far-future deadline (e.g. `u64::MAX`) would give no MEV protection.
+    fn require_valid_deadline(env: &Env, deadline: u64) -> Result<(), Error> {
+        let now = env.ledger().timestamp();
+        if now > deadline {
+            return Err(Error::TransactionExpired);
+        }
+
+        let max_deadline_window = storage::get_max_deadline_window(env);
+        if max_deadline_window > 0 && deadline - now > max_deadline_window {
+            return Err(Error::DeadlineTooFar);
+        }
+
+        Ok(())
+    }
+
+    /// Check if a token must graduate after a trade
+    ///
+    /// Graduates once XLM raised reaches the threshold, or once the sold share of the
+    /// curve supply exceeds `max_supply_sold_bps` (keeps tokens back for AMM liquidity).
+    fn should_graduate(env: &Env, xlm_raised: i128, bonding_curve: &BondingCurve) -> Result<bool, Error> {
+        if xlm_raised >= GRADUATION_THRESHOLD {
+            return Ok(true);
+        }
+
+        let supply_cap = math::apply_bps(bonding_curve.total_supply, storage::get_max_supply_sold_bps(env))?;
+        Ok(bonding_curve.tokens_sold > supply_cap)
+    }
+
+    /// Graduation progress for an XLM raised amount (0-10000 = 0%-100%)
+    fn graduation_progress_bps(xlm_raised: i128) -> i128 {
+        let progress = xlm_raised
+            .checked_mul(10_000)
+            .and_then(|v| v.checked_div(GRADUATION_THRESHOLD))
+            .unwrap_or(10_000); // If overflow, assume 100%
+        progress.min(10_000)
+    }
+
+    /// Execute a buy against the bonding curve
+    ///
+    /// Shared by all buy entry points. Callers are responsible for `require_auth`.
+    /// `payer` funds the buy; `recipient` receives the tokens and is the buyer for
+    /// limits, trade stats and events. `is_dev_buy` marks the creator's launch-time
+    /// buy, which skips the anti-snipe delay.
+    #[allow(clippy::too_many_arguments)]
+    fn process_buy(
+        env: &Env,
+        payer: &Address,
+        recipient: &Address,
+        token: &Address,
+        xlm_amount: i128,
+        min_tokens: i128,
+        deadline: u64,
+        is_dev_buy: bool,
+    ) -> Result<i128, Error> {
+        // 1. INPUT VALIDATION: Verify amounts are positive
+        if xlm_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if min_tokens < 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        // 2. MEV PROTECTION: Verify deadline
+        Self::require_valid_deadline(env, deadline)?;
+
+        // 3. Check contract is active and trading is open
+        state_management::require_active(env)?;
+        state_management::require_trading_active(env)?;
+
+        // 4. Get token info
+        let mut token_info = storage::get_token_info(env, token)
+            .ok_or(Error::TokenNotFound)?;
+
+        // 5. Check if still in bonding curve phase
+        if token_info.status != TokenStatus::Bonding {
+            return Err(Error::AlreadyGraduated);
+        }
+
+        // ANTI-SNIPE: Trading opens after the creator's launch delay
+        if !is_dev_buy {
+            Self::require_trading_started(env, &token_info)?;
+        }
+
+        // FAIRNESS: Per-address cap on XLM spent within a rolling day (dev buy included)
+        if token_info.daily_buy_limit > 0 {
+            storage::record_daily_buy(env, token, recipient, xlm_amount, token_info.daily_buy_limit)?;
+        }
+
+        // 6. CRITICAL FIX: Transfer XLM from payer to contract FIRST
+        // Note: In production, this performs a real XLM transfer via the native XLM SAC
+        // TODO: In tests, we need to mock the XLM token properly
+        // For now, we skip XLM transfers in test mode to allow tests to pass
+        #[cfg(not(test))]
+        {
+            let quote_client = token::Client::new(env, &token_info.quote_asset);
+            let contract_address = env.current_contract_address();
+
+            // Transfer quote asset (XLM unless chosen at launch) from payer to contract
+            quote_client.transfer(payer, &contract_address, &xlm_amount);
+        }
+
+        // 6. Get price before trade (for slippage calculation)
+        let price_before = token_info.bonding_curve.get_current_price();
+
+        // 7-8. Calculate tokens from bonding curve and apply trading fee
+        let fee_config = fee_management::get_fee_config(env)?;
+        let (xlm_to_curve, tokens_gross, tokens_net, fee_amount) =
+            Self::quote_buy(env, &token_info.bonding_curve, xlm_amount)?;
+
+        // 9. Check slippage
+        if tokens_net < min_tokens {
+            return Err(Error::SlippageExceeded);
+        }
+
+        // 10. CRITICAL FIX: Transfer tokens from contract to recipient
+        // TODO: In tests, we need to mint tokens to the contract first
+        // For now, we skip token transfers in test mode
+        #[cfg(not(test))]
+        {
+            let contract_address = env.current_contract_address();
+            let token_client = token::Client::new(env, token);
+            token_client.transfer(&contract_address, recipient, &tokens_net);
+        }
+
+        if fee_config.fee_in_xlm {
+            fee_management::send_quote_fee_to_treasury(env, &token_info.quote_asset, fee_amount)?;
+        } else {
+            // Token fee: burn the configured share, the rest goes to treasury
+            let burned = fee_management::distribute_token_fee(env, token, tokens_gross, fee_amount)?;
+            token_info.tokens_burned = math::safe_add(token_info.tokens_burned, burned)?;
+        }
+
+        // 11. Update bonding curve state (with gross amount)
+        token_info.bonding_curve.execute_buy(xlm_to_curve, tokens_gross)?;
+
+        // 12. Get price after trade
+        let price_after = token_info.bonding_curve.get_current_price();
+
+        // 13. Calculate actual slippage
+        let slippage_bps = math::calculate_slippage_bps(price_before, price_after)?;
+
+        // 14. Update total XLM raised (XLM that entered the curve)
+        token_info.xlm_raised = math::safe_add(token_info.xlm_raised, xlm_to_curve)?;
+        if token_info.quote_asset == Self::get_xlm_token_address(env) {
+            storage::adjust_total_xlm_locked(env, xlm_to_curve)?;
+            storage::adjust_curve_xlm_backing(env, xlm_to_curve)?;
+        }
+        token_info.total_volume_xlm = math::safe_add(token_info.total_volume_xlm, xlm_amount)?;
+        let fee_in_quote = if fee_config.fee_in_xlm {
+            fee_amount
+        } else {
+            math::mul_div(xlm_amount, fee_amount, tokens_gross)?
+        };
+        token_info.fees_collected = math::safe_add(token_info.fees_collected, fee_in_quote)?;
+        token_info.trade_count = token_info.trade_count.saturating_add(1);
+        if !storage::record_trade_direction(env, token, recipient, true) {
+            token_info.organic_volume_xlm = math::safe_add(token_info.organic_volume_xlm, xlm_amount)?;
+        }
+        storage::mark_traded(env, token, recipient);
+
+        // 15. Update market cap (XLM raised * 2 for constant product)
+        token_info.market_cap = math::safe_mul(token_info.xlm_raised, 2)?;
+
+        // 16. Check for auto-graduation (XLM threshold or supply cap; never for perpetual curves)
+        // Graduation is part of this buy: a failure here reverts the entire buy,
+        // including the XLM transfer above (see graduate_to_amm for the fairness model)
+        if token_info.graduation_enabled
+            && Self::should_graduate(env, token_info.xlm_raised, &token_info.bonding_curve)?
+        {
+            Self::graduate_to_amm(env, &mut token_info, payer)?;
+        }
+
+        // 17. Save state
+        storage::set_token_info(env, token, &token_info);
+        if token_info.price_history_enabled {
+            storage::record_price_snapshot(env, token, price_after, token_info.xlm_raised);
+        }
+
+        // 18. Emit events (both basic and detailed)
+        events::tokens_bought(env, recipient, token, xlm_amount, tokens_net);
+        events::tokens_bought_detailed(
+            env,
+            recipient,
+            payer,
+            token,
+            &events::BuyDetails {
+                xlm_amount,
+                tokens_gross,
+                fee_amount,
+                fee_bps: fee_management::effective_fee_bps(fee_amount, fee_config.buy_fee_bps),
+                tokens_net,
+                price_before,
+                price_after,
+                slippage_bps,
+            },
+        );
+
+        Ok(tokens_net)
+    }
+
+    /// Shared launch checks: factory state, allowlist, name, symbol and metadata
+    fn validate_launch(
+        env: &Env,
+        creator: &Address,
+        name: &String,
+        symbol: &String,
+        image_url: &String,
+        description: &String,
+    ) -> Result<(), Error> {
+        // Check contract is active and launches are open
+        state_management::require_active(env)?;
+        state_management::require_launches_active(env)?;
+
+        // Curated mode: only allowlisted creators may launch
+        if storage::get_launch_whitelist_enabled(env) && !storage::is_launch_allowed(env, creator) {
+            return Err(Error::NotWhitelisted);
+        }
+
+        // Validate inputs
+        if name.len() == 0 || name.len() > 32 {
+            return Err(Error::InvalidName);
+        }
+        if symbol.len() == 0 || symbol.len() > 12 {
+            return Err(Error::InvalidSymbol);
+        }
+        if storage::get_strict_charset(env) {
+            Self::validate_strict_charset(name, symbol)?;
+        }
+        if image_url.len() > MAX_IMAGE_URL_LENGTH || description.len() > MAX_DESCRIPTION_LENGTH {
+            return Err(Error::InvalidMetadata);
+        }
+
+        Ok(())
+    }
+
+    /// Reject malformed or already launched assets before deploying
+    ///
+    /// The SAC address is deterministic: reuse of an already launched asset
+    /// (same code + issuer) must fail cleanly, not trap at deploy.
+    fn require_new_asset(env: &Env, serialized_asset: &Bytes) -> Result<(), Error> {
+        sac_deployment::validate_serialized_asset(serialized_asset)?;
+        let predicted_address = sac_deployment::get_sac_address(env, serialized_asset.clone());
+        if storage::get_token_info(env, &predicted_address).is_some() {
+            return Err(Error::TokenAlreadyExists);
+        }
+
+        Ok(())
+    }
+
     /// Deploy a real SAC token using client-provided serialized asset
     fn deploy_sac_token(
         env: &Env,
@@ -654,28 +2391,49 @@ impl SacFactory {
     /// **Sprint 2 - Complete Graduation Flow:**
     /// 1. Deploy new AMM pair contract
     /// 2. Initialize AMM with token and XLM
-    /// 3. Transfer bonding curve liquidity to AMM
-    /// 4. Add initial liquidity
+    /// 3. Send the graduation fee (if `graduation_fee_bps` > 0) to treasury
+    /// 4. Add initial liquidity (the pair pulls the remaining bonding curve
+    ///    liquidity from the factory, once)
     /// 5. Burn LP tokens (permanent liquidity lock)
-    /// 6. Mark token as graduated
+    /// 6. Carve out creator allocation (if `graduation_creator_bps` > 0)
+    /// 7. Mark token as graduated
+    ///
+    /// # Fairness
+    /// Graduation runs inside the buy that crosses the threshold: the curve
+    /// closes, the pool is seeded and `GraduationDetailed` is emitted in that one
+    /// transaction, or the whole buy reverts. No other transaction can trade
+    /// between the last curve trade and the pool opening, so the earliest anyone
+    /// can react is the next transaction in ledger order.
+    ///
+    /// The pool opens at the curve's final price (see `check_seed_price`), so
+    /// the first AMM swap finds no price gap to arbitrage. The triggering buyer
+    /// gets no AMM position or other reward: a reward would make the threshold
+    /// crossing itself the thing to front-run. The event names them
+    /// (`graduated_by`) for indexers.
     ///
     /// # Arguments
     /// * `env` - Contract environment
     /// * `token_info` - Mutable reference to token info
+    /// * `graduated_by` - Payer of the buy that crossed the threshold
     ///
     /// # Returns
     /// Ok(()) on success, Error on failure
-    fn graduate_to_amm(env: &Env, token_info: &mut TokenInfo) -> Result<(), Error> {
-        // Get XLM token address
-        let xlm_address = Self::get_xlm_token_address(env);
+    fn graduate_to_amm(env: &Env, token_info: &mut TokenInfo, graduated_by: &Address) -> Result<(), Error> {
+        // Never deploy an AMM during an emergency halt, whatever the caller checked
+        state_management::require_active(env)?;
+        state_management::require_trading_active(env)?;
+
+        // Pair the token with its curve's quote asset (XLM unless chosen at launch)
+        let quote_address = token_info.quote_asset.clone();
 
         // Get contract addresses
         let factory_address = env.current_contract_address();
-        let fee_config = fee_management::get_fee_config(env);
+        let fee_config = fee_management::get_fee_config(env)?;
 
         // 0. Validate market cap with oracle (if configured)
+        // The oracle prices XLM, so the check only applies to XLM-quoted curves
         let min_market_cap = storage::get_min_market_cap_usd(env);
-        if min_market_cap > 0 {
+        if min_market_cap > 0 && quote_address == Self::get_xlm_token_address(env) {
             // Oracle validation is required
             if let Ok(oracle_client) = price_oracle::get_oracle_client(env) {
                 let meets_requirement = oracle_client
@@ -690,62 +2448,67 @@ impl SacFactory {
         }
 
         // 1. Deploy AMM pair contract
+        let amm_fee_to = Self::amm_fee_to(env)?;
         let amm_address = amm_deployment::deploy_amm_pair(
             env,
-            &xlm_address,
+            &quote_address,
             &token_info.token_address,
             &factory_address,
-            &fee_config.treasury,
+            &amm_fee_to,
         )?;
 
         // 2. Calculate liquidity amounts
-        // - XLM: All collected from bonding curve
-        // - Tokens: Remaining tokens in bonding curve reserve
-        let xlm_liquidity = token_info.bonding_curve.xlm_reserve;
-        let token_liquidity = token_info.bonding_curve.tokens_remaining;
-
-        // Validation: Ensure we have sufficient liquidity
-        if xlm_liquidity <= 0 || token_liquidity <= 0 {
-            return Err(Error::InsufficientLiquidityForGraduation);
-        }
+        let GraduationAmounts {
+            graduation_fee,
+            xlm_liquidity,
+            token_liquidity,
+            creator_allocation,
+            excess_tokens,
+            excess_xlm,
+        } = Self::graduation_amounts(env, token_info, &fee_config)?;
 
         // 3. Initialize AMM pair
         let amm_client = amm_client::AmmPairClient::new(env, amm_address.clone());
         amm_client.initialize(
-            &xlm_address,
+            &quote_address,
             &token_info.token_address,
             &factory_address,
-            &fee_config.treasury,
+            &amm_fee_to,
         )?;
 
-        // 4. Transfer liquidity to AMM
-        #[cfg(not(test))]
-        {
-            // Transfer XLM from factory to AMM
-            let xlm_token_client = token::Client::new(env, &xlm_address);
-            xlm_token_client.transfer(&factory_address, &amm_address, &xlm_liquidity);
-
-            // Transfer tokens from factory to AMM
-            let token_client = token::Client::new(env, &token_info.token_address);
-            token_client.transfer(&factory_address, &amm_address, &token_liquidity);
-
-            // 5. Add initial liquidity to AMM
-            // Factory is the sender, LP tokens will be minted to factory (permanent lock)
-            let deadline = env.ledger().timestamp() + 300; // 5 minutes from now
-
-            let (_amount_0, _amount_1, liquidity_minted) = amm_client.add_liquidity(
-                &factory_address,
-                xlm_liquidity,          // amount_0_desired (XLM)
-                token_liquidity,        // amount_1_desired (graduated token)
-                0,                      // amount_0_min (no slippage for initial)
-                0,                      // amount_1_min (no slippage for initial)
-                deadline,
-            )?;
+        // Refuse to seed a pool someone already deposited into
+        amm_client.require_empty()?;
+
+        // 4. Take the graduation fee
+        fee_management::send_quote_fee_to_treasury(env, &quote_address, graduation_fee)?;
+
+        // 5. Add initial liquidity to AMM (the pair pulls both sides from the
+        // factory; amounts as reported by the pair)
+        let (tokens_graduated, lp_tokens_locked) = Self::seed_amm_liquidity(
+            env,
+            &amm_client,
+            &quote_address,
+            &token_info.token_address,
+            xlm_liquidity,
+            token_liquidity,
+        )?;
 
-            // LP tokens are now minted to factory address and locked permanently
-            // Factory never moves these tokens = permanent liquidity lock
-            // Emit liquidity lock event for transparency
-            events::liquidity_locked(env, &amm_address, liquidity_minted);
+        // Dispose of whatever the price match held back: excess tokens are
+        // burned from the factory, excess XLM goes to the treasury
+        if excess_tokens > 0 {
+            token::Client::new(env, &token_info.token_address).burn(&factory_address, &excess_tokens);
+        }
+        token_info.tokens_burned = math::safe_add(token_info.tokens_burned, excess_tokens)?;
+        fee_management::send_quote_fee_to_treasury(env, &quote_address, excess_xlm)?;
+
+        // Carve out creator allocation (claimable via claim_creator_allocation)
+        if creator_allocation > 0 {
+            let claimable = storage::get_creator_claimable(env, &token_info.token_address);
+            storage::set_creator_claimable(
+                env,
+                &token_info.token_address,
+                math::safe_add(claimable, creator_allocation)?,
+            );
         }
 
         // 6. Store AMM pair address
@@ -754,11 +2517,258 @@ impl SacFactory {
             &amm_address,
         );
 
-        // 7. Mark as graduated
+        // 7. Mark as graduated (its XLM has left the factory). Only the pool's
+        // share stays locked; the fee and any excess went to the treasury
         token_info.status = TokenStatus::Graduated;
+        storage::add_graduated_token(env, &token_info.token_address);
+        if quote_address == Self::get_xlm_token_address(env) {
+            storage::adjust_curve_xlm_backing(env, -token_info.xlm_raised)?;
+            storage::adjust_total_xlm_locked(env, -math::safe_add(graduation_fee, excess_xlm)?)?;
+        }
 
-        // 8. Emit graduation event
+        // 8. Emit graduation events (basic and detailed)
         events::token_graduated(env, &token_info.token_address, token_info.xlm_raised);
+        events::graduation_detailed(
+            env,
+            &token_info.token_address,
+            token_info.xlm_raised,
+            tokens_graduated,
+            &amm_address,
+            lp_tokens_locked,
+            creator_allocation,
+            graduation_fee,
+            graduated_by,
+        );
+
+        Ok(())
+    }
+
+    /// Protocol fee recipient for new AMM pairs (`amm_fee_to`, default the treasury)
+    fn amm_fee_to(env: &Env) -> Result<Address, Error> {
+        match storage::get_amm_fee_to(env) {
+            Some(fee_to) => Ok(fee_to),
+            None => Ok(fee_management::get_fee_config(env)?.treasury),
+        }
+    }
+
+    /// Split a graduating curve's reserves between treasury, pool, creator and burn
+    ///
+    /// - The graduation fee (`graduation_fee_bps` of the raised XLM) goes to the
+    ///   treasury; the rest seeds the pool
+    /// - Seeded at the curve's final price, so graduation opens no arbitrage:
+    ///   with less XLM, proportionally fewer tokens are paired
+    /// - Tokens: Enough of the unsold curve tokens plus the off-curve reserve
+    ///   (minus creator allocation) to match
+    /// - Excess tokens are burned; excess XLM (only when the creator allocation
+    ///   leaves too few tokens) goes to the treasury
+    ///
+    /// Fails with `InsufficientLiquidityForGraduation` if either pool side ends
+    /// up below `MIN_GRADUATION_LIQUIDITY`, fee included.
+    fn graduation_amounts(
+        env: &Env,
+        token_info: &TokenInfo,
+        fee_config: &fee_management::FeeConfig,
+    ) -> Result<GraduationAmounts, Error> {
+        let graduation_fee = math::apply_bps(token_info.xlm_raised, fee_config.graduation_fee_bps)?;
+        let seed_xlm = math::safe_sub(token_info.xlm_raised, graduation_fee)?;
+
+        let token_reserve = math::safe_add(token_info.bonding_curve.tokens_remaining, token_info.reserved_supply)?;
+        let creator_allocation = math::apply_bps(token_reserve, fee_config.graduation_creator_bps)?;
+        let available_tokens = math::safe_sub(token_reserve, creator_allocation)?;
+        let (xlm_liquidity, token_liquidity) = token_info
+            .bonding_curve
+            .graduation_liquidity(seed_xlm, available_tokens)?;
+
+        // Validation: Ensure we have sufficient liquidity for a functional pool
+        if xlm_liquidity < MIN_GRADUATION_LIQUIDITY || token_liquidity < MIN_GRADUATION_LIQUIDITY {
+            return Err(Error::InsufficientLiquidityForGraduation);
+        }
+
+        // Validation: The pool must open at (close to) the curve's final price
+        token_info.bonding_curve.check_seed_price(
+            xlm_liquidity,
+            token_liquidity,
+            storage::get_graduation_price_tolerance_bps(env),
+        )?;
+
+        Ok(GraduationAmounts {
+            graduation_fee,
+            xlm_liquidity,
+            token_liquidity,
+            creator_allocation,
+            excess_tokens: math::safe_sub(available_tokens, token_liquidity)?,
+            excess_xlm: math::safe_sub(seed_xlm, xlm_liquidity)?,
+        })
+    }
+
+    /// Seed and record a `launch_to_amm` pair once the SAC and pair are deployed
+    ///
+    /// Takes the creator's XLM, deposits it with `token_liquidity` tokens, sets
+    /// the rest of the supply aside for the creator and stores `token_info`
+    /// (already `Graduated`). Emits the launch and graduation events.
+    fn open_direct_pair(
+        env: &Env,
+        token_info: &TokenInfo,
+        amm_address: &Address,
+        xlm_liquidity: i128,
+        token_liquidity: i128,
+    ) -> Result<(), Error> {
+        let creator = &token_info.creator;
+        let token_address = &token_info.token_address;
+        let factory_address = env.current_contract_address();
+
+        let amm_client = amm_client::AmmPairClient::new(env, amm_address.clone());
+        amm_client.initialize(&token_info.quote_asset, token_address, &factory_address, &Self::amm_fee_to(env)?)?;
+        amm_client.require_empty()?;
+
+        // The pair pulls the seed from the factory, so bring the creator's XLM in
+        token::Client::new(env, &token_info.quote_asset).transfer(creator, &factory_address, &xlm_liquidity);
+
+        let (tokens_deposited, lp_tokens_locked) = Self::seed_amm_liquidity(
+            env,
+            &amm_client,
+            &token_info.quote_asset,
+            token_address,
+            xlm_liquidity,
+            token_liquidity,
+        )?;
+
+        // The rest of the supply is the creator's (claim_creator_allocation)
+        let creator_allocation = math::safe_sub(INITIAL_SUPPLY, token_liquidity)?;
+        if creator_allocation > 0 {
+            storage::set_creator_claimable(env, token_address, creator_allocation);
+        }
+
+        storage::set_token_info(env, token_address, token_info);
+        storage::add_creator_token(env, creator, token_address);
+        storage::set_token_by_id(env, token_info.id, token_address);
+        storage::increment_token_count(env);
+        env.storage().persistent().set(
+            &storage::PersistentKey::AmmPairAddress(token_address.clone()),
+            amm_address,
+        );
+        storage::add_graduated_token(env, token_address);
+
+        // The pool's XLM counts toward TVL like a graduated curve's
+        if token_info.quote_asset == Self::get_xlm_token_address(env) {
+            storage::adjust_total_xlm_locked(env, xlm_liquidity)?;
+        }
+
+        events::token_launched(env, creator, token_address, &token_info.name, &token_info.symbol);
+        events::graduation_detailed(
+            env,
+            token_address,
+            0,
+            tokens_deposited,
+            amm_address,
+            lp_tokens_locked,
+            creator_allocation,
+            0,
+            creator,
+        );
+
+        Ok(())
+    }
+
+    /// Make the seed deposit into a freshly initialized pair
+    ///
+    /// Factory is the sender, so LP tokens are minted to the factory and locked
+    /// permanently (the factory never moves them). Amounts are ordered the way
+    /// the pair sorts `quote_asset` and `token`, so a quote asset that sorts
+    /// after the token (often USDC) still lands on its own side.
+    ///
+    /// # Returns
+    /// (graduated tokens actually deposited, LP tokens locked) as reported by the pair
+    fn seed_amm_liquidity(
+        env: &Env,
+        amm_client: &amm_client::AmmPairClient,
+        quote_asset: &Address,
+        token: &Address,
+        xlm_liquidity: i128,
+        token_liquidity: i128,
+    ) -> Result<(i128, i128), Error> {
+        let deadline = env.ledger().timestamp() + 300; // 5 minutes from now
+        let quote_first = amm_deployment::quote_is_token_0(quote_asset, token);
+        let (amount_0, amount_1) = if quote_first {
+            (xlm_liquidity, token_liquidity)
+        } else {
+            (token_liquidity, xlm_liquidity)
+        };
+
+        // The pair pulls both amounts from the factory, a call the factory
+        // doesn't make itself, so authorize those two transfers up front
+        let factory_address = env.current_contract_address();
+        let (token_0, token_1) = if quote_first { (quote_asset, token) } else { (token, quote_asset) };
+        env.authorize_as_current_contract(vec![
+            env,
+            Self::transfer_auth(env, token_0, &factory_address, amm_client.address(), amount_0),
+            Self::transfer_auth(env, token_1, &factory_address, amm_client.address(), amount_1),
+        ]);
+
+        let (deposited_0, deposited_1, liquidity_minted) = amm_client.add_liquidity(
+            &env.current_contract_address(),
+            amount_0,               // amount_0_desired
+            amount_1,               // amount_1_desired
+            amount_0,               // amount_0_min (exact seed, no slippage)
+            amount_1,               // amount_1_min (exact seed, no slippage)
+            deadline,
+        )?;
+
+        // Emit liquidity lock event for transparency
+        events::liquidity_locked(env, amm_client.address(), liquidity_minted);
+
+        let tokens_deposited = if quote_first { deposited_1 } else { deposited_0 };
+        Ok((tokens_deposited, liquidity_minted))
+    }
+
+    /// Authorization entry for a `transfer` of `amount` of `asset` from `from` to `to`
+    fn transfer_auth(env: &Env, asset: &Address, from: &Address, to: &Address, amount: i128) -> InvokerContractAuthEntry {
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: asset.clone(),
+                fn_name: symbol_short!("transfer"),
+                args: (from.clone(), to.clone(), amount).into_val(env),
+            },
+            sub_invocations: vec![env],
+        })
+    }
+
+    /// Resolve the quote asset chosen at launch (None = native XLM)
+    ///
+    /// Curve math, thresholds and fees assume 7 decimals, so only 7-decimal
+    /// assets (XLM, Stellar USDC, ...) can quote a curve.
+    fn resolve_quote_asset(env: &Env, quote_asset: &Option<Address>) -> Result<Address, Error> {
+        let xlm_address = Self::get_xlm_token_address(env);
+        let quote_asset = match quote_asset {
+            None => return Ok(xlm_address),
+            Some(quote_asset) if *quote_asset == xlm_address => return Ok(xlm_address),
+            Some(quote_asset) => quote_asset.clone(),
+        };
+
+        match token::Client::new(env, &quote_asset).try_decimals() {
+            Ok(Ok(7)) => Ok(quote_asset),
+            _ => Err(Error::InvalidQuoteAsset),
+        }
+    }
+
+    /// Strict charset check for launches (see `set_strict_charset`)
+    ///
+    /// Names must be printable ASCII (no control or zero-width characters, no
+    /// emoji); symbols must be uppercase A-Z and 0-9. Lengths are checked first.
+    fn validate_strict_charset(name: &String, symbol: &String) -> Result<(), Error> {
+        let mut name_buf = [0u8; 32];
+        let name_bytes = &mut name_buf[..name.len() as usize];
+        name.copy_into_slice(name_bytes);
+        if !name_bytes.iter().all(|b| (0x20..=0x7e).contains(b)) {
+            return Err(Error::InvalidName);
+        }
+
+        let mut symbol_buf = [0u8; 12];
+        let symbol_bytes = &mut symbol_buf[..symbol.len() as usize];
+        symbol.copy_into_slice(symbol_bytes);
+        if !symbol_bytes.iter().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit()) {
+            return Err(Error::InvalidSymbol);
+        }
 
         Ok(())
     }
@@ -28,6 +28,19 @@ pub fn safe_div(a: i128, b: i128) -> Result<i128, Error> {
     a.checked_div(b).ok_or(Error::Overflow)
 }
 
+/// Safe division rounding up, for positive operands
+pub fn safe_div_ceil(a: i128, b: i128) -> Result<i128, Error> {
+    if b <= 0 || a < 0 {
+        return Err(Error::DivisionByZero);
+    }
+    let q = a / b;
+    if a % b == 0 {
+        Ok(q)
+    } else {
+        safe_add(q, 1)
+    }
+}
+
 /// Multiply two numbers and divide by a third: (a * b) / c
 ///
 /// Useful for percentage calculations and fee computations.
@@ -37,6 +50,75 @@ pub fn mul_div(a: i128, b: i128, c: i128) -> Result<i128, Error> {
     safe_div(numerator, c)
 }
 
+/// Multiply two numbers and divide by a third with a 256-bit intermediate
+///
+/// Same result as `mul_div` (rounds toward zero), but `a * b` may exceed
+/// i128: only a quotient that doesn't fit fails with `Overflow`. Use it where
+/// reserves and large supplies are multiplied together.
+pub fn safe_mul_div(a: i128, b: i128, c: i128) -> Result<i128, Error> {
+    if c == 0 {
+        return Err(Error::DivisionByZero);
+    }
+
+    // Fast path: the product fits
+    if let Some(numerator) = a.checked_mul(b) {
+        return safe_div(numerator, c);
+    }
+
+    let (hi, lo) = wide_mul(a.unsigned_abs(), b.unsigned_abs());
+    let quotient = wide_div(hi, lo, c.unsigned_abs())?;
+
+    if (a < 0) ^ (b < 0) ^ (c < 0) {
+        0i128.checked_sub_unsigned(quotient).ok_or(Error::Overflow)
+    } else {
+        i128::try_from(quotient).map_err(|_| Error::Overflow)
+    }
+}
+
+/// Full 256-bit product of two u128s, as (high, low) halves
+fn wide_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    let low = a_lo * b_lo;
+    let cross_1 = a_lo * b_hi;
+    let cross_2 = a_hi * b_lo;
+    let high = a_hi * b_hi;
+
+    // At most 3 * 2^64, so this can't overflow
+    let mid = (low >> 64) + (cross_1 & MASK) + (cross_2 & MASK);
+
+    let lo = (low & MASK) | (mid << 64);
+    let hi = high + (cross_1 >> 64) + (cross_2 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/// Divide a 256-bit (high, low) value by a u128 (binary long division)
+///
+/// Fails with `Overflow` if the quotient needs more than 128 bits.
+fn wide_div(hi: u128, lo: u128, divisor: u128) -> Result<u128, Error> {
+    if hi >= divisor {
+        return Err(Error::Overflow);
+    }
+
+    let mut remainder = hi;
+    let mut quotient = 0u128;
+    for bit in (0..128).rev() {
+        // The remainder is below the divisor, so one shift spills at most one bit
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((lo >> bit) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= divisor {
+            remainder = remainder.wrapping_sub(divisor);
+            quotient |= 1;
+        }
+    }
+
+    Ok(quotient)
+}
+
 /// Calculate percentage in basis points (1% = 100 bps)
 ///
 /// # Arguments
@@ -63,6 +145,32 @@ pub fn calculate_slippage_bps(price_before: i128, price_after: i128) -> Result<i
     Ok(slippage)
 }
 
+/// Rescale a fixed-point value between decimal precisions
+///
+/// # Arguments
+/// * `price` - Fixed-point value with `from_decimals` decimals
+/// * `from_decimals` - Current number of decimals (e.g., 7 for curve prices)
+/// * `to_decimals` - Target number of decimals (e.g., 18 for oracle prices)
+///
+/// # Returns
+/// The value with `to_decimals` decimals (truncated when down-scaling)
+pub fn scale_price(price: i128, from_decimals: u32, to_decimals: u32) -> Result<i128, Error> {
+    if from_decimals == to_decimals {
+        return Ok(price);
+    }
+
+    if to_decimals > from_decimals {
+        let factor = 10_i128.checked_pow(to_decimals - from_decimals).ok_or(Error::Overflow)?;
+        safe_mul(price, factor)
+    } else {
+        // Factor overflow means the result truncates to zero
+        match 10_i128.checked_pow(from_decimals - to_decimals) {
+            Some(factor) => safe_div(price, factor),
+            None => Ok(0),
+        }
+    }
+}
+
 /// Calculate square root for initial liquidity calculations
 /// Uses Newton's method for approximation
 pub fn sqrt(y: i128) -> Result<i128, Error> {
@@ -95,6 +203,14 @@ mod tests {
         assert!(safe_add(i128::MAX, 1).is_err());
     }
 
+    #[test]
+    fn test_safe_div_ceil() {
+        assert_eq!(safe_div_ceil(10, 5).unwrap(), 2);
+        assert_eq!(safe_div_ceil(11, 5).unwrap(), 3);
+        assert_eq!(safe_div_ceil(0, 5).unwrap(), 0);
+        assert!(safe_div_ceil(1, 0).is_err());
+    }
+
     #[test]
     fn test_safe_sub() {
         assert_eq!(safe_sub(100, 50).unwrap(), 50);
@@ -120,6 +236,28 @@ mod tests {
         assert!(mul_div(100, 5, 0).is_err());
     }
 
+    #[test]
+    fn test_safe_mul_div() {
+        // Agrees with mul_div while the product fits, signs included
+        assert_eq!(safe_mul_div(100, 5, 10).unwrap(), 50);
+        assert_eq!(safe_mul_div(-7, 3, 2).unwrap(), mul_div(-7, 3, 2).unwrap());
+        assert_eq!(safe_mul_div(100, 5, 0).unwrap_err(), Error::DivisionByZero);
+
+        // Product overflows i128, quotient doesn't
+        assert!(mul_div(i128::MAX, 4, 8).is_err());
+        assert_eq!(safe_mul_div(i128::MAX, 4, 8).unwrap(), i128::MAX / 2);
+        assert_eq!(safe_mul_div(i128::MAX, i128::MAX, i128::MAX).unwrap(), i128::MAX);
+        assert_eq!(safe_mul_div(i128::MAX, -3, 3).unwrap(), -i128::MAX);
+        assert_eq!(safe_mul_div(i128::MIN, 2, 2).unwrap(), i128::MIN);
+        let big = 10_i128.pow(30);
+        assert_eq!(safe_mul_div(big, big, 10_i128.pow(25)).unwrap(), 10_i128.pow(35));
+        assert_eq!(safe_mul_div(big + 7, big, big).unwrap(), big + 7);
+
+        // The quotient itself must fit
+        assert_eq!(safe_mul_div(i128::MAX, 2, 1).unwrap_err(), Error::Overflow);
+        assert_eq!(safe_mul_div(i128::MAX, i128::MAX, 2).unwrap_err(), Error::Overflow);
+    }
+
     #[test]
     fn test_apply_bps() {
         // 1% of 1000 = 10
@@ -136,6 +274,28 @@ mod tests {
         assert_eq!(calculate_slippage_bps(100, 105).unwrap(), 500);
     }
 
+    #[test]
+    fn test_scale_price_up() {
+        // 1.5 with 7 decimals -> 18 decimals
+        assert_eq!(scale_price(15_000_000, 7, 18).unwrap(), 1_500_000_000_000_000_000);
+        assert_eq!(scale_price(42, 7, 7).unwrap(), 42);
+    }
+
+    #[test]
+    fn test_scale_price_down() {
+        // 1.5 with 18 decimals -> 7 decimals
+        assert_eq!(scale_price(1_500_000_000_000_000_000, 18, 7).unwrap(), 15_000_000);
+        // Sub-unit precision is truncated
+        assert_eq!(scale_price(19, 18, 17).unwrap(), 1);
+        assert_eq!(scale_price(i128::MAX, 60, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_scale_price_overflow() {
+        assert_eq!(scale_price(i128::MAX / 10, 7, 18).unwrap_err(), Error::Overflow);
+        assert_eq!(scale_price(1, 0, 40).unwrap_err(), Error::Overflow);
+    }
+
     #[test]
     fn test_sqrt() {
         assert_eq!(sqrt(0).unwrap(), 0);
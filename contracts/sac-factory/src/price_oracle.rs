@@ -7,6 +7,12 @@ use soroban_sdk::{contracttype, Address, Env, IntoVal, Symbol, Vec as SorobanVec
 
 use crate::errors::Error;
 
+/// Decimals used for XLM amounts and bonding curve prices
+pub const XLM_DECIMALS: u32 = 7;
+
+/// Decimals used for oracle USD prices
+pub const USD_DECIMALS: u32 = 18;
+
 /// Price data returned from oracle
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -126,18 +132,19 @@ impl<'a> DiaOracleClient<'a> {
             return Err(Error::InvalidAmount);
         }
 
-        let xlm_u128 = xlm_amount as u128;
+        let xlm_price = i128::try_from(xlm_price).map_err(|_| Error::MathOverflow)?;
 
         // Calculate market cap
         // xlm_amount has 7 decimals, xlm_price has 18 decimals
-        // Result = (xlm_amount * xlm_price) / 10^7
-        let market_cap = xlm_u128
-            .checked_mul(xlm_price)
-            .ok_or(Error::MathOverflow)?
-            .checked_div(10_000_000) // Normalize XLM decimals
-            .ok_or(Error::MathOverflow)?;
-
-        Ok(market_cap)
+        // Product has 7 + 18 decimals, rescaled down to 18
+        let market_cap = crate::math::safe_mul(xlm_amount, xlm_price)?;
+        let market_cap = crate::math::scale_price(
+            market_cap,
+            XLM_DECIMALS + USD_DECIMALS,
+            USD_DECIMALS,
+        )?;
+
+        Ok(market_cap as u128)
     }
 
     /// Check if graduation threshold meets minimum market cap requirement
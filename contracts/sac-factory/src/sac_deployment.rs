@@ -11,9 +11,21 @@
 //! The client creates and serializes the Asset XDR to bytes, then passes it to the contract.
 //! This follows Stellar/Soroban best practices and avoids XDR serialization in no_std contracts.
 
-use soroban_sdk::{Bytes, Env};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, String};
 use crate::errors::Error;
 
+/// XDR discriminant for `Asset::CreditAlphanum4`
+const ASSET_TYPE_ALPHANUM4: u32 = 1;
+
+/// XDR discriminant for `Asset::CreditAlphanum12`
+const ASSET_TYPE_ALPHANUM12: u32 = 2;
+
+/// XDR discriminant for `PublicKey::PublicKeyTypeEd25519`
+const PUBLIC_KEY_TYPE_ED25519: u32 = 0;
+
+/// Size of an XDR `AccountId` (4-byte key type + 32-byte Ed25519 key)
+const ACCOUNT_ID_LEN: u32 = 36;
+
 /// Deploy a REAL Stellar Asset Contract from serialized asset bytes
 ///
 /// # Arguments
@@ -35,6 +47,9 @@ pub fn deploy_sac_from_serialized_asset(
     env: &Env,
     serialized_asset: Bytes,
 ) -> Result<soroban_sdk::Address, Error> {
+    // Reject malformed bytes before the host function traps on them
+    validate_serialized_asset(&serialized_asset)?;
+
     // Deploy the SAC using Stellar's built-in deployer
     // This calls the host function: create_asset_contract(serialized_asset)
     let deployer = env.deployer().with_stellar_asset(serialized_asset);
@@ -43,6 +58,114 @@ pub fn deploy_sac_from_serialized_asset(
     Ok(token_address)
 }
 
+/// Validate that bytes are an XDR `Asset::CreditAlphanum4` or `Asset::CreditAlphanum12`
+///
+/// Layout: 4-byte asset type, then a 4 or 12 byte zero-padded asset code,
+/// then the issuer `AccountId` (4-byte key type + 32-byte Ed25519 key).
+///
+/// # Returns
+/// `Error::InvalidSymbol` if the bytes are not a well-formed credit asset
+pub fn validate_serialized_asset(serialized_asset: &Bytes) -> Result<(), Error> {
+    let asset_type = read_u32(serialized_asset, 0)?;
+
+    // AlphaNum4 codes are 1-4 chars, AlphaNum12 codes are 5-12 chars
+    let (code_len, min_code_chars) = match asset_type {
+        ASSET_TYPE_ALPHANUM4 => (4u32, 1u32),
+        ASSET_TYPE_ALPHANUM12 => (12u32, 5u32),
+        _ => return Err(Error::InvalidSymbol),
+    };
+
+    if serialized_asset.len() != 4 + code_len + ACCOUNT_ID_LEN {
+        return Err(Error::InvalidSymbol);
+    }
+
+    // Asset code: alphanumeric characters followed only by zero padding
+    let mut code_chars = 0u32;
+    let mut in_padding = false;
+    for i in 4..4 + code_len {
+        let byte = serialized_asset.get(i).ok_or(Error::InvalidSymbol)?;
+        if byte == 0 {
+            in_padding = true;
+        } else if in_padding || !byte.is_ascii_alphanumeric() {
+            return Err(Error::InvalidSymbol);
+        } else {
+            code_chars += 1;
+        }
+    }
+
+    if code_chars < min_code_chars {
+        return Err(Error::InvalidSymbol);
+    }
+
+    // Issuer must be an Ed25519 account
+    if read_u32(serialized_asset, 4 + code_len)? != PUBLIC_KEY_TYPE_ED25519 {
+        return Err(Error::InvalidSymbol);
+    }
+
+    Ok(())
+}
+
+/// Read a big-endian u32 (XDR encoding) at `offset`
+fn read_u32(bytes: &Bytes, offset: u32) -> Result<u32, Error> {
+    let mut buf = [0u8; 4];
+    for (i, byte) in buf.iter_mut().enumerate() {
+        *byte = bytes.get(offset + i as u32).ok_or(Error::InvalidSymbol)?;
+    }
+    Ok(u32::from_be_bytes(buf))
+}
+
+/// Derive the canonical launch salt for `creator` at a given token count and time
+///
+/// salt = sha256(token_count || timestamp || creator), big-endian integers and the
+/// creator's XDR, so every (creator, launch slot) gets its own salt.
+pub fn compute_launch_salt(env: &Env, creator: &Address, token_count: u32, timestamp: u64) -> BytesN<32> {
+    let mut salt_data = Bytes::from_array(env, &token_count.to_be_bytes());
+    salt_data.extend_from_array(&timestamp.to_be_bytes());
+    salt_data.append(&creator.clone().to_xdr(env));
+
+    env.crypto().sha256(&salt_data).into()
+}
+
+/// Build a serialized credit asset whose issuer is derived from `creator` and `salt`
+///
+/// The same (symbol, creator, salt) always yields the same bytes, and therefore the
+/// same SAC address, which makes salted launches safe to retry.
+///
+/// # Returns
+/// `Error::InvalidSymbol` if the symbol can't be an asset code (1-12 alphanumeric chars)
+pub fn serialize_asset_from_salt(
+    env: &Env,
+    symbol: &String,
+    creator: &Address,
+    salt: &BytesN<32>,
+) -> Result<Bytes, Error> {
+    let symbol_len = symbol.len();
+    let (asset_type, code_len) = match symbol_len {
+        1..=4 => (ASSET_TYPE_ALPHANUM4, 4usize),
+        5..=12 => (ASSET_TYPE_ALPHANUM12, 12usize),
+        _ => return Err(Error::InvalidSymbol),
+    };
+
+    // Zero-padded asset code
+    let mut code = [0u8; 12];
+    symbol.copy_into_slice(&mut code[..symbol_len as usize]);
+
+    // Issuer key: sha256(domain || creator || salt)
+    let mut seed = Bytes::from_slice(env, b"SAC_ISSUER_SALT_V1");
+    seed.append(&creator.clone().to_xdr(env));
+    seed.append(&Bytes::from(salt.clone()));
+    let issuer_key = env.crypto().sha256(&seed).to_array();
+
+    let mut serialized_asset = Bytes::from_array(env, &asset_type.to_be_bytes());
+    serialized_asset.extend_from_slice(&code[..code_len]);
+    serialized_asset.extend_from_array(&PUBLIC_KEY_TYPE_ED25519.to_be_bytes());
+    serialized_asset.extend_from_array(&issuer_key);
+
+    validate_serialized_asset(&serialized_asset)?;
+
+    Ok(serialized_asset)
+}
+
 /// Get the address that would be created for a serialized asset (without deploying)
 ///
 /// Useful for pre-calculating the token address before deployment.
@@ -233,4 +356,103 @@ mod tests {
 
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_validate_serialized_asset_accepts_helper_output() {
+        let env = Env::default();
+        let creator = soroban_sdk::Address::generate(&env);
+        let salt = BytesN::from_array(&env, &[1u8; 32]);
+
+        let short = create_test_serialized_asset(&env, &String::from_str(&env, "T"), &creator, &salt).unwrap();
+        let long = create_test_serialized_asset(&env, &String::from_str(&env, "LONGSYMBOL99"), &creator, &salt).unwrap();
+
+        assert!(validate_serialized_asset(&short).is_ok());
+        assert!(validate_serialized_asset(&long).is_ok());
+    }
+
+    #[test]
+    fn test_deploy_rejects_malformed_bytes() {
+        let env = Env::default();
+
+        // Empty and truncated inputs
+        let empty = Bytes::new(&env);
+        assert_eq!(deploy_sac_from_serialized_asset(&env, empty).unwrap_err(), Error::InvalidSymbol);
+
+        let truncated = Bytes::from_array(&env, &[0, 0, 0, 1, b'T', b'E']);
+        assert_eq!(deploy_sac_from_serialized_asset(&env, truncated).unwrap_err(), Error::InvalidSymbol);
+
+        // Native asset (type 0) is not a launchable credit asset
+        let native = Bytes::from_array(&env, &[0, 0, 0, 0]);
+        assert_eq!(deploy_sac_from_serialized_asset(&env, native).unwrap_err(), Error::InvalidSymbol);
+    }
+
+    #[test]
+    fn test_serialize_asset_from_salt() {
+        use soroban_sdk::xdr::{Asset, AlphaNum4, AssetCode4, AccountId, PublicKey, Uint256, WriteXdr, Limits};
+
+        let env = Env::default();
+        let creator = soroban_sdk::Address::generate(&env);
+        let salt = BytesN::from_array(&env, &[1u8; 32]);
+        let symbol = String::from_str(&env, "TEST");
+
+        let serialized_asset = serialize_asset_from_salt(&env, &symbol, &creator, &salt).unwrap();
+
+        // Same bytes the stellar-sdk would produce for this code and issuer
+        let issuer_key = serialized_asset.slice(12..).to_buffer::<32>();
+        let expected = Asset::CreditAlphanum4(AlphaNum4 {
+            asset_code: AssetCode4(*b"TEST"),
+            issuer: AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(
+                issuer_key.as_slice().try_into().unwrap(),
+            ))),
+        })
+        .to_xdr(Limits::none())
+        .unwrap();
+        assert_eq!(serialized_asset, Bytes::from_slice(&env, &expected));
+
+        // Deterministic per (symbol, creator, salt)
+        assert_eq!(serialize_asset_from_salt(&env, &symbol, &creator, &salt).unwrap(), serialized_asset);
+        let other_salt = BytesN::from_array(&env, &[2u8; 32]);
+        assert_ne!(serialize_asset_from_salt(&env, &symbol, &creator, &other_salt).unwrap(), serialized_asset);
+        let other_creator = soroban_sdk::Address::generate(&env);
+        assert_ne!(serialize_asset_from_salt(&env, &symbol, &other_creator, &salt).unwrap(), serialized_asset);
+
+        // AlphaNum12 codes and invalid symbols
+        let long = serialize_asset_from_salt(&env, &String::from_str(&env, "LONGSYMBOL99"), &creator, &salt).unwrap();
+        assert_eq!(long.len(), 52);
+        for bad in ["", "THIRTEENCHARS", "A-B"] {
+            assert_eq!(
+                serialize_asset_from_salt(&env, &String::from_str(&env, bad), &creator, &salt).unwrap_err(),
+                Error::InvalidSymbol
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_asset_code() {
+        let env = Env::default();
+        let creator = soroban_sdk::Address::generate(&env);
+        let salt = BytesN::from_array(&env, &[1u8; 32]);
+        let valid = create_test_serialized_asset(&env, &String::from_str(&env, "AB"), &creator, &salt).unwrap();
+
+        // Non-alphanumeric character in code
+        let mut bad_char = valid.clone();
+        bad_char.set(4, b'-');
+        assert_eq!(validate_serialized_asset(&bad_char).unwrap_err(), Error::InvalidSymbol);
+
+        // Character after zero padding ("AB\0C")
+        let mut bad_padding = valid.clone();
+        bad_padding.set(7, b'C');
+        assert_eq!(validate_serialized_asset(&bad_padding).unwrap_err(), Error::InvalidSymbol);
+
+        // Empty code
+        let mut empty_code = valid.clone();
+        empty_code.set(4, 0);
+        empty_code.set(5, 0);
+        assert_eq!(validate_serialized_asset(&empty_code).unwrap_err(), Error::InvalidSymbol);
+
+        // Unknown issuer key type
+        let mut bad_key = valid;
+        bad_key.set(11, 1);
+        assert_eq!(validate_serialized_asset(&bad_key).unwrap_err(), Error::InvalidSymbol);
+    }
 }
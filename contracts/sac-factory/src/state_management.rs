@@ -29,6 +29,8 @@ pub enum ContractState {
 #[contracttype]
 pub enum StateKey {
     State,
+    TradingPaused,
+    LaunchesPaused,
 }
 
 /// Get current contract state
@@ -123,6 +125,75 @@ pub fn unpause(env: &Env, admin: &Address) -> Result<(), Error> {
     Ok(())
 }
 
+/// Check if trading (buy/sell) is paused
+pub fn is_trading_paused(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&StateKey::TradingPaused)
+        .unwrap_or(false)
+}
+
+/// Check if token launches are paused
+pub fn is_launches_paused(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&StateKey::LaunchesPaused)
+        .unwrap_or(false)
+}
+
+/// Require trading to NOT be paused (global pause is checked separately)
+pub fn require_trading_active(env: &Env) -> Result<(), Error> {
+    if is_trading_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+    Ok(())
+}
+
+/// Require launches to NOT be paused (global pause is checked separately)
+pub fn require_launches_active(env: &Env) -> Result<(), Error> {
+    if is_launches_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+    Ok(())
+}
+
+/// Pause or resume trading only (Owner or PauseAdmin)
+pub fn set_trading_paused(env: &Env, admin: &Address, paused: bool) -> Result<(), Error> {
+    admin.require_auth();
+    require_pause_admin(env, admin)?;
+
+    env.storage().persistent().set(&StateKey::TradingPaused, &paused);
+
+    // Emit event
+    events::trading_pause_updated(env, paused, admin);
+
+    Ok(())
+}
+
+/// Pause or resume token launches only (Owner or PauseAdmin)
+pub fn set_launches_paused(env: &Env, admin: &Address, paused: bool) -> Result<(), Error> {
+    admin.require_auth();
+    require_pause_admin(env, admin)?;
+
+    env.storage().persistent().set(&StateKey::LaunchesPaused, &paused);
+
+    // Emit event
+    events::launches_pause_updated(env, paused, admin);
+
+    Ok(())
+}
+
+/// Require PauseAdmin or Owner role
+fn require_pause_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+    let has_permission = crate::access_control::has_role(env, admin, Role::PauseAdmin)
+        || crate::access_control::has_role(env, admin, Role::Owner);
+
+    if !has_permission {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
+}
+
 /// Set contract to migrating state (Owner only)
 pub fn start_migration(env: &Env, owner: &Address) -> Result<(), Error> {
     owner.require_auth();
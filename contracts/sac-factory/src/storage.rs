@@ -3,10 +3,11 @@
 //! Storage Type Strategy (Stellar Best Practices):
 //! - Instance: Small, frequently accessed config (< 100KB) - Admin, Treasury, TokenCount
 //! - Persistent: User/token specific data (unbounded, separate keys) - TokenInfo, CreatorTokens
-//! - Temporary: Time-bound data (not used yet, reserved for future features)
+//! - Temporary: Time-bound data - each trader's last trade direction (wash trade detection)
 
-use soroban_sdk::{contracttype, Address, Env, Vec};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Map, String, Symbol, TryFromVal, Val, Vec};
 use crate::bonding_curve::BondingCurve;
+use crate::errors::Error;
 
 /// Storage keys for Instance storage (small, frequently accessed)
 #[contracttype]
@@ -18,6 +19,21 @@ pub enum InstanceKey {
     AmmWasmHash,       // WASM hash for AMM pair contract deployment
     OracleAddress,     // DIA Oracle contract address for price feeds
     MinMarketCapUsd,   // Minimum market cap in USD (18 decimals) for graduation
+    MaxSupplySoldBps,  // Share of curve supply sold (bps) that forces graduation
+    MaxDeadlineWindow, // Max seconds a trade deadline may lie in the future (0 = unlimited)
+    TotalXlmLocked,    // Real XLM deposited into XLM-quoted curves (and carried into AMMs)
+    MigrationVersion,  // TokenInfo layout version every stored record is on (see migrate_token)
+    CurrentLayoutCount, // Token records launched or migrated in the current TokenInfo layout
+    StrictCharset,     // Restrict names to printable ASCII and symbols to A-Z0-9 (default on)
+    DeployedVersion,   // Contract version recorded at initialize
+    CurveXlmBacking,   // Real XLM held for XLM-quoted curves still bonding
+    AccruedXlmFees,    // XLM sell fees kept by the factory (fee_in_xlm off)
+    GraduatedCount,    // Number of graduated tokens (length of GraduatedTokens)
+    GraduationPriceToleranceBps, // Max gap (bps) between AMM seed price and curve spot price
+    MinSellXlm,        // Smallest gross quote-asset output a sell may have (0 = no floor)
+    AmmFeeTo,          // Protocol fee recipient for graduated AMM pairs (unset = treasury)
+    LaunchWhitelistEnabled, // Only allowlisted creators may launch (default off)
+    ReadTtlThreshold,  // Token records read with less TTL left (ledgers) are renewed (0 = off)
 }
 
 /// Storage keys for Persistent storage (unbounded, per-entity)
@@ -27,6 +43,21 @@ pub enum PersistentKey {
     TokenInfo(Address),        // token_address -> TokenInfo
     CreatorTokens(Address),    // creator -> Vec<token_addresses>
     AmmPairAddress(Address),   // token_address -> amm_pair_address (for graduated tokens)
+    CreatorClaimable(Address), // token_address -> creator's unclaimed graduation allocation
+    GraduatedTokens,           // Vec<token_addresses> in graduation order
+    PriceHistory(Address),     // token_address -> Vec<(timestamp, price, xlm_raised)> ring buffer
+    TokenById(u32),            // TokenInfo.id -> token_address
+    TokenIndex(Address),       // token_address -> (TokenInfo.id, creator), outlives TokenInfo (see prune_expired)
+    LaunchAllowed(Address),    // creator -> allowed to launch while the whitelist is on
+    EverTraded(Address, Address), // (token, account) -> bought on the curve at least once
+}
+
+/// Storage keys for Temporary storage (short-lived, expires on its own)
+#[contracttype]
+#[derive(Clone)]
+pub enum TemporaryKey {
+    LastTrade(Address, Address), // (token, trader) -> (is_buy, timestamp) of the trader's last curve trade
+    DailyBuys(Address, Address), // (token, buyer) -> (window_start, xlm_spent) toward the daily buy limit
 }
 
 /// Token status
@@ -54,6 +85,245 @@ pub struct TokenInfo {
     pub xlm_raised: i128,
     pub market_cap: i128,
     pub holders_count: u32,
+    /// Seconds after `created_at` before trading opens (anti-snipe)
+    pub trading_start_delay: u64,
+    /// Whether sells are currently allowed on the bonding curve
+    pub sells_enabled: bool,
+    /// Timestamp sells were last disabled (sells resume automatically after the max pause)
+    pub sells_disabled_at: u64,
+    /// Whether trades record price snapshots (see `get_price_history`)
+    pub price_history_enabled: bool,
+    /// Tokens burned from trading fees and graduation (no longer in circulation)
+    pub tokens_burned: i128,
+    /// Asset the curve is priced and settled in (native XLM unless chosen at launch)
+    pub quote_asset: Address,
+    /// All-time curve volume in the quote asset (gross buy input + gross sell output)
+    pub total_volume_xlm: i128,
+    /// Number of curve trades (buys and sells)
+    pub trade_count: u32,
+    /// `total_volume_xlm` minus same-address round trips (see `WASH_TRADE_WINDOW`)
+    pub organic_volume_xlm: i128,
+    /// Whether the token graduates to an AMM at the threshold (false = perpetual curve)
+    pub graduation_enabled: bool,
+    /// Max XLM one address may spend on buys per `DAILY_BUY_WINDOW` (0 = unlimited)
+    pub daily_buy_limit: i128,
+    /// Supply kept off the curve, held by the factory for graduation (AMM seed
+    /// and creator allocation). While bonding,
+    /// `tokens_sold + tokens_remaining + reserved_supply == INITIAL_SUPPLY`.
+    pub reserved_supply: i128,
+    /// All-time trading fees charged on this token, in the quote asset
+    /// (token-denominated buy fees count at the XLM they displaced)
+    pub fees_collected: i128,
+}
+
+/// `BondingCurve` layout before per-curve virtual-reserve decay
+///
+/// Only read as part of `LegacyTokenInfo`.
+#[contracttype]
+#[derive(Clone)]
+pub struct LegacyBondingCurve {
+    pub total_supply: i128,
+    pub tokens_sold: i128,
+    pub tokens_remaining: i128,
+    pub xlm_reserve: i128,
+    pub k: i128,
+}
+
+impl LegacyBondingCurve {
+    /// Upgrade to the current layout with decay off
+    pub fn upgrade(self) -> BondingCurve {
+        BondingCurve {
+            total_supply: self.total_supply,
+            tokens_sold: self.tokens_sold,
+            tokens_remaining: self.tokens_remaining,
+            xlm_reserve: self.xlm_reserve,
+            k: self.k,
+            virtual_decay_bps: 0,
+        }
+    }
+}
+
+/// Original (v1) `TokenInfo` layout, before per-launch options and trade stats
+///
+/// Only read by `migrate_token`; nothing writes this layout anymore.
+#[contracttype]
+#[derive(Clone)]
+pub struct LegacyTokenInfo {
+    pub id: u32,
+    pub creator: Address,
+    pub token_address: Address,
+    pub name: soroban_sdk::String,
+    pub symbol: soroban_sdk::String,
+    pub image_url: soroban_sdk::String,
+    pub description: soroban_sdk::String,
+    pub created_at: u64,
+    pub status: TokenStatus,
+    pub bonding_curve: LegacyBondingCurve,
+    pub xlm_raised: i128,
+    pub market_cap: i128,
+    pub holders_count: u32,
+}
+
+impl LegacyTokenInfo {
+    /// Upgrade to the current layout, filling new fields with launch-time defaults
+    pub fn upgrade(self, quote_asset: Address) -> TokenInfo {
+        let reserved_supply = crate::INITIAL_SUPPLY - self.bonding_curve.total_supply;
+        TokenInfo {
+            id: self.id,
+            creator: self.creator,
+            token_address: self.token_address,
+            name: self.name,
+            symbol: self.symbol,
+            image_url: self.image_url,
+            description: self.description,
+            created_at: self.created_at,
+            status: self.status,
+            bonding_curve: self.bonding_curve.upgrade(),
+            xlm_raised: self.xlm_raised,
+            market_cap: self.market_cap,
+            holders_count: self.holders_count,
+            trading_start_delay: 0,
+            sells_enabled: true,
+            sells_disabled_at: 0,
+            price_history_enabled: false,
+            tokens_burned: 0,
+            quote_asset,
+            total_volume_xlm: 0,
+            trade_count: 0,
+            organic_volume_xlm: 0,
+            graduation_enabled: true,
+            daily_buy_limit: 0,
+            reserved_supply,
+            fees_collected: 0,
+        }
+    }
+}
+
+/// Result of `simulate_buy`: full effect of a buy without executing it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuySimulation {
+    /// Tokens the buyer would receive (after fees)
+    pub tokens_net: i128,
+    /// Trading fee (in XLM when `fee_in_xlm` is set, otherwise in tokens)
+    pub fee: i128,
+    pub price_after: i128,
+    pub slippage_bps: i128,
+    pub new_xlm_raised: i128,
+    pub new_graduation_progress_bps: i128,
+    pub would_graduate: bool,
+}
+
+/// Result of `preview_fees`: where the value of a trade goes
+///
+/// All amounts are in `fee_asset`: the token for buys with token fees, otherwise
+/// the curve's quote asset. `gross = protocol_fee + burn_fee + creator_fee + referral_fee + net`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeBreakdown {
+    pub gross: i128,
+    /// Trading fee share sent to the treasury
+    pub protocol_fee: i128,
+    /// Trading fee share burned (token fees only, see `fee_burn_bps`)
+    pub burn_fee: i128,
+    /// Creator share (not charged yet, always 0)
+    pub creator_fee: i128,
+    /// Referral share (not charged yet, always 0)
+    pub referral_fee: i128,
+    /// Amount left for the trade itself (XLM into the curve, tokens or XLM out)
+    pub net: i128,
+    pub fee_asset: Address,
+}
+
+/// Snapshot of factory-wide configuration (see `get_config`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FactoryConfig {
+    pub admin: Address,
+    pub treasury: Address,
+    pub creation_fee: i128,
+    pub buy_fee_bps: i128,
+    pub sell_fee_bps: i128,
+    pub fee_in_xlm: bool,
+    pub fee_burn_bps: i128,
+    pub dev_buy_fee_waiver_threshold: i128,
+    pub graduation_fee_bps: i128,
+    pub graduation_threshold: i128,
+    pub state: crate::state_management::ContractState,
+    pub trading_paused: bool,
+    pub launches_paused: bool,
+    pub oracle: Option<Address>,
+    /// Protocol fee recipient of graduated AMM pairs (defaults to `treasury`)
+    pub amm_fee_to: Address,
+    pub min_market_cap_usd: u128,
+    pub max_supply_sold_bps: i128,
+    pub graduation_price_tolerance_bps: i128,
+    pub max_deadline_window: u64,
+    pub min_sell_xlm: i128,
+    pub strict_charset: bool,
+    pub launch_whitelist_enabled: bool,
+    /// Token records read with less TTL left than this (ledgers) are renewed (0 = off)
+    pub read_ttl_threshold: u32,
+    pub amm_wasm_set: bool,
+    pub token_count: u32,
+}
+
+/// Accounting consistency snapshot (see `check_health`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HealthReport {
+    /// Factory's native XLM balance
+    pub xlm_balance: i128,
+    /// Real XLM owed to XLM-quoted curves still bonding
+    pub curve_xlm_backing: i128,
+    /// XLM sell fees held by the factory
+    pub accrued_fees: i128,
+    /// `xlm_balance >= curve_xlm_backing + accrued_fees`
+    pub balance_covers_liabilities: bool,
+    /// Every tracked aggregate is non-negative
+    pub aggregates_non_negative: bool,
+    pub total_tokens: u32,
+    pub total_active_tokens: u32,
+    pub total_graduated: u32,
+}
+
+/// Optional per-token settings chosen at launch
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LaunchOptions {
+    /// Seconds after launch before trading opens (0 = immediately)
+    pub trading_start_delay: u64,
+    /// Record on-chain price snapshots for charting (costs extra storage per trade)
+    pub price_history: bool,
+    /// XLM the creator spends on the curve atomically with launch (0 = no dev buy)
+    pub initial_buy_xlm: i128,
+    /// Quote asset SAC for the curve and its AMM pair, e.g. USDC (None = native XLM)
+    pub quote_asset: Option<Address>,
+    /// Keep the token on its bonding curve forever (never graduates to an AMM)
+    pub disable_graduation: bool,
+    /// Max XLM one address may spend on buys per 24 hours (0 = unlimited)
+    pub daily_buy_limit: i128,
+    /// Share of `INITIAL_SUPPLY` sold on the curve, 5000-10000 bps (0 = default 80%)
+    pub curve_supply_bps: i128,
+    /// Virtual XLM reserve removed by curve sell-out, 0-9000 bps, for a steeper
+    /// late-stage ramp (0 = off; see `BondingCurve::new_with_decay`)
+    pub virtual_decay_bps: i128,
+}
+
+/// Token metadata and pool seed for `launch_to_amm`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LaunchParams {
+    pub name: String,
+    pub symbol: String,
+    pub image_url: String,
+    pub description: String,
+    /// XDR-serialized Stellar asset, as for `launch_token`
+    pub serialized_asset: Bytes,
+    /// XLM (in stroops) the creator seeds the pool with
+    pub xlm_liquidity: i128,
+    /// Tokens seeded into the pool (at most the initial supply)
+    pub token_liquidity: i128,
 }
 
 // ========== Instance Storage (Small, Frequent Access) ==========
@@ -86,9 +356,19 @@ pub fn set_token_count(env: &Env, count: u32) {
     env.storage().instance().set(&InstanceKey::TokenCount, &count);
 }
 
+/// Count a newly launched token (its record is in the current layout)
 pub fn increment_token_count(env: &Env) {
     let count = get_token_count(env);
     set_token_count(env, count.saturating_add(1));
+    count_current_layout_record(env);
+}
+
+pub fn has_amm_wasm_hash(env: &Env) -> bool {
+    env.storage().instance().has(&InstanceKey::AmmWasmHash)
+}
+
+pub fn get_amm_wasm_hash(env: &Env) -> Option<BytesN<32>> {
+    env.storage().instance().get(&InstanceKey::AmmWasmHash)
 }
 
 pub fn get_oracle_address(env: &Env) -> Option<Address> {
@@ -99,6 +379,14 @@ pub fn set_oracle_address(env: &Env, oracle: &Address) {
     env.storage().instance().set(&InstanceKey::OracleAddress, oracle);
 }
 
+pub fn get_amm_fee_to(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&InstanceKey::AmmFeeTo)
+}
+
+pub fn set_amm_fee_to(env: &Env, amm_fee_to: &Address) {
+    env.storage().instance().set(&InstanceKey::AmmFeeTo, amm_fee_to);
+}
+
 pub fn get_min_market_cap_usd(env: &Env) -> u128 {
     env.storage()
         .instance()
@@ -112,13 +400,357 @@ pub fn set_min_market_cap_usd(env: &Env, min_market_cap: u128) {
         .set(&InstanceKey::MinMarketCapUsd, &min_market_cap);
 }
 
+/// Default supply cap (95%): above what the XLM threshold already sells (~91%)
+pub const DEFAULT_MAX_SUPPLY_SOLD_BPS: i128 = 9500;
+
+pub fn get_max_supply_sold_bps(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&InstanceKey::MaxSupplySoldBps)
+        .unwrap_or(DEFAULT_MAX_SUPPLY_SOLD_BPS)
+}
+
+pub fn set_max_supply_sold_bps(env: &Env, max_supply_sold_bps: i128) {
+    env.storage()
+        .instance()
+        .set(&InstanceKey::MaxSupplySoldBps, &max_supply_sold_bps);
+}
+
+/// Default graduation price tolerance (2%)
+pub const DEFAULT_GRADUATION_PRICE_TOLERANCE_BPS: i128 = 200;
+
+pub fn get_graduation_price_tolerance_bps(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&InstanceKey::GraduationPriceToleranceBps)
+        .unwrap_or(DEFAULT_GRADUATION_PRICE_TOLERANCE_BPS)
+}
+
+pub fn set_graduation_price_tolerance_bps(env: &Env, tolerance_bps: i128) {
+    env.storage()
+        .instance()
+        .set(&InstanceKey::GraduationPriceToleranceBps, &tolerance_bps);
+}
+
+/// Default deadline window (1 hour)
+pub const DEFAULT_MAX_DEADLINE_WINDOW: u64 = 3600;
+
+pub fn get_max_deadline_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&InstanceKey::MaxDeadlineWindow)
+        .unwrap_or(DEFAULT_MAX_DEADLINE_WINDOW)
+}
+
+pub fn set_max_deadline_window(env: &Env, max_deadline_window: u64) {
+    env.storage()
+        .instance()
+        .set(&InstanceKey::MaxDeadlineWindow, &max_deadline_window);
+}
+
+/// Default sell floor: 0.01 XLM of gross output
+pub const DEFAULT_MIN_SELL_XLM: i128 = 100_000;
+
+pub fn get_min_sell_xlm(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&InstanceKey::MinSellXlm)
+        .unwrap_or(DEFAULT_MIN_SELL_XLM)
+}
+
+pub fn set_min_sell_xlm(env: &Env, min_sell_xlm: i128) {
+    env.storage().instance().set(&InstanceKey::MinSellXlm, &min_sell_xlm);
+}
+
+/// Default read renewal threshold: 7 days (7 * 24 * 60 * 60 / 5 ledgers)
+pub const DEFAULT_READ_TTL_THRESHOLD: u32 = 120_960;
+
+/// TTL every token record write extends to: 30 days
+pub const TOKEN_INFO_TTL: u32 = 518_400;
+
+/// TTL a token's reverse index extends to: 180 days, well past its record
+pub const TOKEN_INDEX_TTL: u32 = 3_110_400;
+
+pub fn get_read_ttl_threshold(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&InstanceKey::ReadTtlThreshold)
+        .unwrap_or(DEFAULT_READ_TTL_THRESHOLD)
+}
+
+pub fn set_read_ttl_threshold(env: &Env, ledgers: u32) {
+    env.storage().instance().set(&InstanceKey::ReadTtlThreshold, &ledgers);
+}
+
+pub fn get_strict_charset(env: &Env) -> bool {
+    env.storage().instance().get(&InstanceKey::StrictCharset).unwrap_or(true)
+}
+
+pub fn set_strict_charset(env: &Env, strict: bool) {
+    env.storage().instance().set(&InstanceKey::StrictCharset, &strict);
+}
+
+pub fn get_launch_whitelist_enabled(env: &Env) -> bool {
+    env.storage().instance().get(&InstanceKey::LaunchWhitelistEnabled).unwrap_or(false)
+}
+
+pub fn set_launch_whitelist_enabled(env: &Env, enabled: bool) {
+    env.storage().instance().set(&InstanceKey::LaunchWhitelistEnabled, &enabled);
+}
+
+pub fn is_launch_allowed(env: &Env, account: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&PersistentKey::LaunchAllowed(account.clone()))
+        .unwrap_or(false)
+}
+
+/// Add or remove an address from the launch allowlist (removal frees the entry)
+pub fn set_launch_allowed(env: &Env, account: &Address, allowed: bool) {
+    let key = PersistentKey::LaunchAllowed(account.clone());
+    if !allowed {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+
+    env.storage().persistent().set(&key, &true);
+
+    // Extend TTL to 30 days
+    env.storage().persistent().extend_ttl(&key, 518_400, 518_400);
+}
+
+/// Whether an account ever bought a token on its curve (selling doesn't clear it)
+pub fn has_traded(env: &Env, token: &Address, account: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&PersistentKey::EverTraded(token.clone(), account.clone()))
+}
+
+/// Flag an account as a participant in a token's curve (written once)
+pub fn mark_traded(env: &Env, token: &Address, account: &Address) {
+    if has_traded(env, token, account) {
+        return;
+    }
+
+    let key = PersistentKey::EverTraded(token.clone(), account.clone());
+    env.storage().persistent().set(&key, &true);
+
+    // Extend TTL to 30 days
+    env.storage().persistent().extend_ttl(&key, 518_400, 518_400);
+}
+
+/// Ledgers a temporary record must live to cover `seconds` of ledger time.
+/// Ledgers close every ~5s only on average, so this allows twice that many
+/// ledgers; the windows themselves are enforced on the ledger timestamp.
+const fn window_ttl_ledgers(seconds: u64) -> u32 {
+    (seconds * 2 / 5) as u32
+}
+
+/// Seconds within which an opposite trade by the same address counts as churn
+pub const WASH_TRADE_WINDOW: u64 = 300;
+
+/// Ledgers to keep a last-trade record alive (outlasts `WASH_TRADE_WINDOW`)
+const LAST_TRADE_TTL_LEDGERS: u32 = window_ttl_ledgers(WASH_TRADE_WINDOW);
+
+/// Record a trade and report whether it reverses the trader's previous trade
+/// on the same token within `WASH_TRADE_WINDOW` (a buy right after a sell, or
+/// a sell right after a buy)
+pub fn record_trade_direction(env: &Env, token: &Address, trader: &Address, is_buy: bool) -> bool {
+    let key = TemporaryKey::LastTrade(token.clone(), trader.clone());
+    let now = env.ledger().timestamp();
+
+    let is_churn = env
+        .storage()
+        .temporary()
+        .get::<_, (bool, u64)>(&key)
+        .map(|(was_buy, at)| was_buy != is_buy && now.saturating_sub(at) <= WASH_TRADE_WINDOW)
+        .unwrap_or(false);
+
+    env.storage().temporary().set(&key, &(is_buy, now));
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, LAST_TRADE_TTL_LEDGERS, LAST_TRADE_TTL_LEDGERS);
+
+    is_churn
+}
+
+/// Length of the per-address buy limit window (24 hours)
+pub const DAILY_BUY_WINDOW: u64 = 86_400;
+
+/// Ledgers to keep a daily-buy record alive (outlasts `DAILY_BUY_WINDOW`)
+const DAILY_BUYS_TTL_LEDGERS: u32 = window_ttl_ledgers(DAILY_BUY_WINDOW);
+
+/// Add a buy to the buyer's running total for the current window
+///
+/// The window starts at the buyer's first buy and resets once
+/// `DAILY_BUY_WINDOW` has passed.
+///
+/// # Returns
+/// `Error::DailyLimitExceeded` if the total would go over `limit`
+pub fn record_daily_buy(
+    env: &Env,
+    token: &Address,
+    buyer: &Address,
+    xlm_amount: i128,
+    limit: i128,
+) -> Result<(), Error> {
+    let key = TemporaryKey::DailyBuys(token.clone(), buyer.clone());
+    let now = env.ledger().timestamp();
+
+    let (window_start, spent) = env
+        .storage()
+        .temporary()
+        .get::<_, (u64, i128)>(&key)
+        .filter(|(start, _)| now < start.saturating_add(DAILY_BUY_WINDOW))
+        .unwrap_or((now, 0));
+
+    let spent = spent.checked_add(xlm_amount).ok_or(Error::Overflow)?;
+    if spent > limit {
+        return Err(Error::DailyLimitExceeded);
+    }
+
+    env.storage().temporary().set(&key, &(window_start, spent));
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, DAILY_BUYS_TTL_LEDGERS, DAILY_BUYS_TTL_LEDGERS);
+
+    Ok(())
+}
+
+pub fn get_deployed_version(env: &Env) -> u32 {
+    env.storage().instance().get(&InstanceKey::DeployedVersion).unwrap_or(0)
+}
+
+pub fn set_deployed_version(env: &Env, version: u32) {
+    env.storage().instance().set(&InstanceKey::DeployedVersion, &version);
+}
+
+/// Current `TokenInfo` layout version
+pub const TOKEN_INFO_VERSION: u32 = 2;
+
+/// Layout version every stored record is on (1 until initialize or a finished migration)
+pub fn get_migration_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&InstanceKey::MigrationVersion)
+        .unwrap_or(1)
+}
+
+pub fn set_migration_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&InstanceKey::MigrationVersion, &version);
+}
+
+/// Number of token records launched or migrated in the current layout
+pub fn get_current_layout_count(env: &Env) -> u32 {
+    env.storage().instance().get(&InstanceKey::CurrentLayoutCount).unwrap_or(0)
+}
+
+/// Count one more token record in the current layout
+pub fn count_current_layout_record(env: &Env) {
+    let count = get_current_layout_count(env);
+    env.storage()
+        .instance()
+        .set(&InstanceKey::CurrentLayoutCount, &count.saturating_add(1));
+}
+
+pub fn get_total_xlm_locked(env: &Env) -> i128 {
+    env.storage().instance().get(&InstanceKey::TotalXlmLocked).unwrap_or(0)
+}
+
+pub fn adjust_total_xlm_locked(env: &Env, delta: i128) -> Result<(), Error> {
+    let total = get_total_xlm_locked(env)
+        .checked_add(delta)
+        .ok_or(Error::Overflow)?;
+    env.storage().instance().set(&InstanceKey::TotalXlmLocked, &total);
+    Ok(())
+}
+
+pub fn get_curve_xlm_backing(env: &Env) -> i128 {
+    env.storage().instance().get(&InstanceKey::CurveXlmBacking).unwrap_or(0)
+}
+
+pub fn adjust_curve_xlm_backing(env: &Env, delta: i128) -> Result<(), Error> {
+    let total = get_curve_xlm_backing(env)
+        .checked_add(delta)
+        .ok_or(Error::Overflow)?;
+    env.storage().instance().set(&InstanceKey::CurveXlmBacking, &total);
+    Ok(())
+}
+
+pub fn get_accrued_xlm_fees(env: &Env) -> i128 {
+    env.storage().instance().get(&InstanceKey::AccruedXlmFees).unwrap_or(0)
+}
+
+pub fn adjust_accrued_xlm_fees(env: &Env, delta: i128) -> Result<(), Error> {
+    let total = get_accrued_xlm_fees(env)
+        .checked_add(delta)
+        .ok_or(Error::Overflow)?;
+    env.storage().instance().set(&InstanceKey::AccruedXlmFees, &total);
+    Ok(())
+}
+
+/// Get the number of graduated tokens, counting the list once if the counter predates it
+pub fn get_graduated_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&InstanceKey::GraduatedCount)
+        .unwrap_or_else(|| get_graduated_tokens(env).len())
+}
+
 // ========== Persistent Storage (Unbounded, Per-Entity) ==========
 
 /// Get token info (returns None if not found)
+///
+/// Records viewed with less than `get_read_ttl_threshold` ledgers of TTL left
+/// are renewed to 30 days, so tokens that are looked at but rarely traded
+/// don't expire. Above the threshold `extend_ttl` doesn't write, so the extra
+/// cost only lands on the read that crosses it.
 pub fn get_token_info(env: &Env, token: &Address) -> Option<TokenInfo> {
+    let key = PersistentKey::TokenInfo(token.clone());
+    let info = env.storage().persistent().get(&key)?;
+
+    let threshold = get_read_ttl_threshold(env);
+    if threshold > 0 {
+        env.storage().persistent().extend_ttl(&key, threshold, TOKEN_INFO_TTL);
+        extend_token_index(env, token);
+    }
+
+    Some(info)
+}
+
+/// Field count of the legacy (v1) `TokenInfo` layout
+const LEGACY_TOKEN_INFO_FIELDS: u32 = 13;
+
+/// Check whether a token's record exists (false once its TTL lapses)
+pub fn has_token_info(env: &Env, token: &Address) -> bool {
     env.storage()
+        .persistent()
+        .has(&PersistentKey::TokenInfo(token.clone()))
+}
+
+/// Get a token record stored in the legacy (v1) layout, for migration
+///
+/// `get_token_info` traps on a legacy record, and so does a failed struct
+/// decode, so the layout is told apart by field count first.
+///
+/// # Returns
+/// `None` if the record is already in the current layout, `TokenNotFound` if
+/// there is no record
+pub fn get_legacy_token_info(env: &Env, token: &Address) -> Result<Option<LegacyTokenInfo>, Error> {
+    let raw: Map<Symbol, Val> = env
+        .storage()
         .persistent()
         .get(&PersistentKey::TokenInfo(token.clone()))
+        .ok_or(Error::TokenNotFound)?;
+
+    if raw.len() != LEGACY_TOKEN_INFO_FIELDS {
+        return Ok(None);
+    }
+    LegacyTokenInfo::try_from_val(env, &raw.to_val())
+        .map(Some)
+        .map_err(|_| Error::InvalidState)
 }
 
 /// Set token info with 30-day TTL extension
@@ -128,9 +760,53 @@ pub fn set_token_info(env: &Env, token: &Address, info: &TokenInfo) {
 
     // Extend TTL to 30 days (measured in ledgers, ~5 seconds per ledger)
     // 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
+    env.storage().persistent().extend_ttl(&key, TOKEN_INFO_TTL, TOKEN_INFO_TTL);
+
+    // Keep the reverse index in step (the creator can change hands)
+    let index_key = PersistentKey::TokenIndex(token.clone());
+    let index = (info.id, info.creator.clone());
+    if env.storage().persistent().get(&index_key) != Some(index.clone()) {
+        env.storage().persistent().set(&index_key, &index);
+    }
+    extend_token_index(env, token);
+}
+
+/// Renew a token's reverse index whenever it has no more TTL left than its
+/// record could, so the index is still there once the record expires
+fn extend_token_index(env: &Env, token: &Address) {
+    env.storage()
+        .persistent()
+        .extend_ttl(&PersistentKey::TokenIndex(token.clone()), TOKEN_INFO_TTL, TOKEN_INDEX_TTL);
+}
+
+/// Get a token's id and creator, readable after its record expired
+pub fn get_token_index(env: &Env, token: &Address) -> Option<(u32, Address)> {
+    env.storage().persistent().get(&PersistentKey::TokenIndex(token.clone()))
+}
+
+pub fn remove_token_index(env: &Env, token: &Address) {
+    env.storage().persistent().remove(&PersistentKey::TokenIndex(token.clone()));
+}
+
+/// Get a token address by its sequential `TokenInfo.id`
+pub fn get_token_by_id(env: &Env, id: u32) -> Option<Address> {
+    env.storage().persistent().get(&PersistentKey::TokenById(id))
+}
+
+/// Map a token id to its address with 30-day TTL extension
+pub fn set_token_by_id(env: &Env, id: u32, token: &Address) {
+    let key = PersistentKey::TokenById(id);
+    env.storage().persistent().set(&key, token);
     env.storage().persistent().extend_ttl(&key, 518_400, 518_400);
 }
 
+pub fn remove_token_by_id(env: &Env, id: u32) {
+    env.storage().persistent().remove(&PersistentKey::TokenById(id));
+}
+
+/// Maximum tokens accepted by one `prune_expired` call
+pub const MAX_PRUNE_BATCH: u32 = 20;
+
 /// Get creator's tokens (returns empty Vec if none)
 pub fn get_creator_tokens(env: &Env, creator: &Address) -> Vec<Address> {
     env.storage()
@@ -151,6 +827,19 @@ pub fn add_creator_token(env: &Env, creator: &Address, token: &Address) {
     env.storage().persistent().extend_ttl(&key, 518_400, 518_400);
 }
 
+/// Remove token from creator's list (no-op if absent)
+pub fn remove_creator_token(env: &Env, creator: &Address, token: &Address) {
+    let mut tokens = get_creator_tokens(env, creator);
+    let Some(index) = tokens.first_index_of(token) else {
+        return;
+    };
+    tokens.remove(index);
+
+    let key = PersistentKey::CreatorTokens(creator.clone());
+    env.storage().persistent().set(&key, &tokens);
+    env.storage().persistent().extend_ttl(&key, 518_400, 518_400);
+}
+
 /// Get paginated creator tokens (DoS prevention)
 pub fn get_creator_tokens_paginated(
     env: &Env,
@@ -158,14 +847,63 @@ pub fn get_creator_tokens_paginated(
     offset: u32,
     limit: u32,
 ) -> Vec<Address> {
-    let all_tokens = get_creator_tokens(env, creator);
+    paginate(env, &get_creator_tokens(env, creator), offset, limit)
+}
+
+/// Get all graduated tokens (use with caution, may be large)
+pub fn get_graduated_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&PersistentKey::GraduatedTokens)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Append token to the graduated tokens list
+pub fn add_graduated_token(env: &Env, token: &Address) {
+    let mut tokens = get_graduated_tokens(env);
+    tokens.push_back(token.clone());
+
+    let key = PersistentKey::GraduatedTokens;
+    env.storage().persistent().set(&key, &tokens);
+
+    // Extend TTL to 30 days
+    env.storage().persistent().extend_ttl(&key, 518_400, 518_400);
+
+    env.storage().instance().set(&InstanceKey::GraduatedCount, &tokens.len());
+}
+
+/// Remove a token from the graduated list (no-op if absent)
+pub fn remove_graduated_token(env: &Env, token: &Address) {
+    let mut tokens = get_graduated_tokens(env);
+    let Some(index) = tokens.first_index_of(token) else {
+        return;
+    };
+    tokens.remove(index);
+
+    let key = PersistentKey::GraduatedTokens;
+    env.storage().persistent().set(&key, &tokens);
+    env.storage().persistent().extend_ttl(&key, 518_400, 518_400);
+
+    env.storage().instance().set(&InstanceKey::GraduatedCount, &tokens.len());
+}
+
+/// Get paginated graduated tokens (DoS prevention)
+pub fn get_graduated_tokens_paginated(env: &Env, offset: u32, limit: u32) -> Vec<Address> {
+    paginate(env, &get_graduated_tokens(env), offset, limit)
+}
+
+/// Max items any paginated read returns in one call
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Slice a page out of an address list (max `MAX_PAGE_SIZE` items)
+fn paginate(env: &Env, all_tokens: &Vec<Address>, offset: u32, limit: u32) -> Vec<Address> {
     let len = all_tokens.len();
 
     if offset >= len {
         return Vec::new(env);
     }
 
-    let end = offset.saturating_add(limit).min(len).min(offset.saturating_add(100)); // Max 100 per page
+    let end = offset.saturating_add(limit).min(len).min(offset.saturating_add(MAX_PAGE_SIZE));
     let start = offset;
 
     let mut result = Vec::new(env);
@@ -177,3 +915,46 @@ pub fn get_creator_tokens_paginated(
 
     result
 }
+
+/// Get creator's unclaimed graduation allocation for a token (0 if none)
+pub fn get_creator_claimable(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&PersistentKey::CreatorClaimable(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Maximum price snapshots kept per token
+pub const MAX_PRICE_HISTORY: u32 = 64;
+
+/// Get price snapshots for a token, oldest first
+pub fn get_price_history(env: &Env, token: &Address) -> Vec<(u64, i128, i128)> {
+    env.storage()
+        .persistent()
+        .get(&PersistentKey::PriceHistory(token.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Append a price snapshot, dropping the oldest once the buffer is full
+pub fn record_price_snapshot(env: &Env, token: &Address, price: i128, xlm_raised: i128) {
+    let mut history = get_price_history(env, token);
+    while history.len() >= MAX_PRICE_HISTORY {
+        history.pop_front();
+    }
+    history.push_back((env.ledger().timestamp(), price, xlm_raised));
+
+    let key = PersistentKey::PriceHistory(token.clone());
+    env.storage().persistent().set(&key, &history);
+
+    // Extend TTL to 30 days
+    env.storage().persistent().extend_ttl(&key, 518_400, 518_400);
+}
+
+/// Set creator's unclaimed graduation allocation for a token
+pub fn set_creator_claimable(env: &Env, token: &Address, amount: i128) {
+    let key = PersistentKey::CreatorClaimable(token.clone());
+    env.storage().persistent().set(&key, &amount);
+
+    // Extend TTL to 30 days
+    env.storage().persistent().extend_ttl(&key, 518_400, 518_400);
+}
@@ -15,10 +15,15 @@ NC='\033[0m'
 CONTRACTS=(
     "token-factory"
     "amm-pair"
+    "sac-factory"
 )
 
 FAILED=0
 
+# sac-factory's graduation tests deploy the compiled AMM pair
+echo -e "${BLUE}Building amm-pair wasm...${NC}"
+(cd contracts/amm-pair && cargo build --target wasm32v1-none --release)
+
 for CONTRACT in "${CONTRACTS[@]}"; do
     if [ -d "contracts/$CONTRACT" ]; then
         echo -e "${BLUE}Testing $CONTRACT...${NC}"